@@ -3,7 +3,9 @@ use std::io::{self, Stdin};
 use pachislo::{
     CONFIG_EXAMPLE as CONFIG, Game, START_HOLE_PROBABILITY_EXAMPLE,
     command::{Command, FinishGame, LaunchBallFlowProducer, StartGame},
-    game::{GameState, Transition},
+    config::Ceiling,
+    event::GameEvent,
+    game::{Bonus, GameError, GameState, Transition},
     interface::{UserInput, UserOutput},
     lottery::LotteryResult,
     slot::SlotProducer,
@@ -61,6 +63,8 @@ impl UserOutput for CuiOutput {
         let Transition {
             before,
             after: state,
+            hold,
+            bonus,
         } = state;
 
         match (state, before) {
@@ -69,13 +73,17 @@ impl UserOutput for CuiOutput {
                 println!();
                 return;
             }
-            (GameState::Normal { .. }, Some(GameState::Rush { n, .. })) => {
-                println!("RUSH finished!, Number of RUSH times: {n}")
+            (GameState::Normal { .. }, Some(GameState::Jitan { .. })) => {
+                println!("JITAN finished!")
             }
             _ => {}
         }
 
         println!("Current state: {state:?}");
+        println!("Hold: {}", "●".repeat(hold.pending));
+        if let Some(bonus) = bonus {
+            println!("BONUS: Round {}/{}", bonus.round, bonus.rounds);
+        }
         println!();
     }
 
@@ -101,6 +109,29 @@ impl UserOutput for CuiOutput {
         Self::print_slot(slot);
         println!("Lottery result in rush continue: {result:?}");
     }
+
+    fn bonus_round(&mut self, bonus: Bonus) {
+        println!("Round {} finished!", bonus.round);
+    }
+
+    fn ceiling(&mut self, ceiling: Ceiling) {
+        println!("CEILING reached after {} spins!", ceiling.spins);
+    }
+
+    fn command_rejected(&mut self, error: GameError) {
+        match error {
+            GameError::Uninitialized(_) => {
+                println!("The game is not started. Press \"s\" to start.")
+            }
+            GameError::AlreadyStarted(_) => println!("The game is already started."),
+        }
+    }
+
+    fn event(&mut self, event: GameEvent) {
+        if let GameEvent::RushEnded { n, gained } = event {
+            println!("RUSH finished!, Number of RUSH times: {n}, Balls gained: {gained}");
+        }
+    }
 }
 
 impl CuiOutput {
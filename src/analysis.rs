@@ -0,0 +1,643 @@
+use std::{error::Error, fmt::Display};
+
+use crate::{
+    config::{
+        Config, ConfigError, Payout, RoundWeight, RushContinueFn, RushModel, SlotProbability,
+    },
+    slot::{EffectStage, ReachConfig, ReachWeights},
+};
+
+/// Probability mass below which a rush is considered finished.
+const TOLERANCE: f64 = 1e-12;
+
+/// Longest rush chain followed before giving up; the rest is reported as truncated.
+const MAX_CHAIN: usize = 10_000;
+
+/// Most rush balls followed, in rounds worth of `incremental_rush`; rushes holding
+/// more are reported as truncated.
+///
+/// A rush that wins more balls per round than it uses up never ends, and following
+/// its ever growing ball count would take forever.
+const MAX_RUSH_BALL_ROUNDS: usize = 64;
+
+/// Exact expectations of a machine configuration, derived without simulation.
+///
+/// A play is split into cycles: launches in normal mode until a jackpot, followed by
+/// the rush it triggers with probability `rush_entry`. The rush is analysed as a Markov chain over the chain length
+/// `n` and the remaining rush balls (or ST spins), so every number is exact up to the
+/// truncation reported in `truncated`.
+///
+/// Ratios follow the definitions of [`SimulationReport`](crate::simulate::SimulationReport),
+/// so simulation results can be checked against them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analysis {
+    /// Probability that a launch in normal mode hits a jackpot.
+    pub normal_hit_rate: f64,
+    /// Probability that a rush ends after `n` rounds: `chain_distribution[n - 1]`.
+    pub chain_distribution: Vec<f64>,
+    /// Probability mass of rushes still running when the analysis stopped, or holding
+    /// too many rush balls to follow (as when every round gains more balls than it uses).
+    pub truncated: f64,
+    /// Expected number of rounds of a rush, ignoring the truncated mass.
+    pub expected_chain: f64,
+    /// Expected number of jackpots won during a rush (excluding the one entering it).
+    pub rush_jackpots: f64,
+    /// Expected net balls gained by a jackpot won in normal mode.
+    pub balls_per_jackpot: f64,
+    /// Expected net balls gained by a jackpot won in rush.
+    pub balls_per_rush_jackpot: f64,
+    /// Expected net balls gained by a normal mode jackpot and the whole rush it may trigger.
+    pub balls_per_hit: f64,
+    /// Expected number of ball launches per cycle (normal mode, bonuses and rush).
+    pub launches_per_cycle: f64,
+    /// Long-run balls returned per ball paid in.
+    ///
+    /// Paid are the launches in normal mode and into the attacker, as in
+    /// [`SimulationReport::paid_launches`](crate::simulate::SimulationReport::paid_launches);
+    /// rush launches are free.
+    pub payout_rate: f64,
+}
+
+/// Error returned when a configuration cannot be analysed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnalysisError {
+    /// The configuration contains invalid values.
+    Config(ConfigError),
+    /// The configuration uses a mechanic the analysis does not model.
+    Unsupported(&'static str),
+}
+
+impl Display for AnalysisError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config(error) => write!(f, "{error}"),
+            Self::Unsupported(reason) => write!(f, "Unsupported configuration: {reason}"),
+        }
+    }
+}
+
+impl Error for AnalysisError {}
+
+impl From<ConfigError> for AnalysisError {
+    fn from(error: ConfigError) -> Self {
+        Self::Config(error)
+    }
+}
+
+/// Derives the exact expectations of a configuration.
+///
+/// Supported are both rush models and the optional payout structure. Mechanics that
+/// depend on the timing of individual spins are not modelled and rejected:
+/// `spin_duration` other than 0 (hold), `jitan_spins`, `kakuhen_entry`, a `ceiling`,
+/// and a `payout` with a `hold_capacity` other than 0 (entries held during a bonus).
+///
+/// # Arguments
+///
+/// * `config` - Machine configuration to analyse
+/// * `start_hole_probability` - Probability (0.0 to 1.0) that a launched ball enters the start hole
+///
+/// # Returns
+///
+/// - `Ok(Analysis)` with the expectations
+/// - `Err(AnalysisError::Config)` if the configuration contains invalid values
+/// - `Err(AnalysisError::Unsupported)` if the configuration uses an unsupported mechanic
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE, analysis::analyze};
+///
+/// let analysis = analyze(CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+///
+/// let total: f64 = analysis.chain_distribution.iter().sum();
+/// assert!((total + analysis.truncated - 1.0).abs() < 1e-9);
+/// assert!(analysis.expected_chain >= 1.0);
+/// ```
+pub fn analyze<F: RushContinueFn>(
+    mut config: Config<F>,
+    start_hole_probability: f64,
+) -> Result<Analysis, AnalysisError> {
+    config.validate()?;
+
+    let balls = config.balls;
+    if !(0.0..=1.0).contains(&start_hole_probability) {
+        return Err(AnalysisError::Unsupported(
+            "start hole probability must be between 0.0 and 1.0",
+        ));
+    }
+    if balls.spin_duration > 0 {
+        return Err(AnalysisError::Unsupported("spin_duration (hold timing)"));
+    }
+    if balls.jitan_spins > 0 {
+        return Err(AnalysisError::Unsupported("jitan_spins"));
+    }
+    if balls.ceiling.is_some() {
+        return Err(AnalysisError::Unsupported("ceiling"));
+    }
+    if config.probability.kakuhen_entry > 0.0 {
+        return Err(AnalysisError::Unsupported("kakuhen_entry"));
+    }
+    if config.payout.is_some() && balls.hold_capacity > 0 {
+        return Err(AnalysisError::Unsupported(
+            "payout with hold_capacity (entries held during a bonus)",
+        ));
+    }
+
+    let s = start_hole_probability;
+    let normal_hit_rate = s * config.probability.normal.win;
+    let rush_win = config.probability.rush.win;
+
+    // Net balls and launches of a single jackpot, from the normal and the rush table
+    let (jackpot, rush_jackpot) = match &config.payout {
+        None => {
+            let jackpot = Jackpot {
+                balls: balls.incremental_balls as f64,
+                launches: 0.0,
+            };
+            (jackpot, jackpot)
+        }
+        Some(payout) => (
+            Jackpot::bonus(payout, &payout.normal),
+            Jackpot::bonus(payout, &payout.rush),
+        ),
+    };
+
+    let rush = match balls.rush_model {
+        RushModel::ContinueLottery => {
+            let continue_win = config.probability.rush_continue.win;
+            let mut per_launch = |n: usize| {
+                let q = continue_win * config.probability.rush_continue_fn.evaluate(n);
+                // An invalid probability skips the continuation lottery
+                if (0.0..=1.0).contains(&q) {
+                    s * rush_win * q
+                } else {
+                    0.0
+                }
+            };
+            continue_lottery_rush(balls.incremental_rush, s * rush_win, &mut per_launch)
+        }
+        RushModel::SpecialTimes { spins } => special_times_rush(spins, s, rush_win),
+    };
+
+    let expected_chain: f64 = rush
+        .chain_distribution
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (i + 1) as f64 * p)
+        .sum();
+
+    // Launches in normal mode until the next jackpot. The ball ending a continue-lottery
+    // rush is drawn in normal mode, giving every cycle after a rush one free draw.
+    let entry = config.probability.rush_entry;
+    let normal_launches = if normal_hit_rate == 0.0 {
+        f64::INFINITY
+    } else if balls.rush_model == RushModel::ContinueLottery {
+        1.0 / normal_hit_rate - entry
+    } else {
+        1.0 / normal_hit_rate
+    };
+
+    let balls_per_hit = jackpot.balls + entry * rush.jackpots * rush_jackpot.balls;
+    let launches_per_cycle = normal_launches
+        + jackpot.launches
+        + entry * (rush.launches + rush.jackpots * rush_jackpot.launches);
+    // Normal mode launches cost a ball each, bonus launches are deducted from their payout
+    let bonus_launches = jackpot.launches + entry * rush.jackpots * rush_jackpot.launches;
+    let paid_per_cycle = normal_launches + bonus_launches;
+    let payout_rate = if paid_per_cycle.is_finite() {
+        (bonus_launches + balls_per_hit) / paid_per_cycle
+    } else {
+        0.0
+    };
+
+    Ok(Analysis {
+        normal_hit_rate,
+        chain_distribution: rush.chain_distribution,
+        truncated: rush.truncated,
+        expected_chain,
+        rush_jackpots: rush.jackpots,
+        balls_per_jackpot: jackpot.balls,
+        balls_per_rush_jackpot: rush_jackpot.balls,
+        balls_per_hit,
+        launches_per_cycle,
+        payout_rate,
+    })
+}
+
+/// Reliability (信頼度) of an observable presentation.
+///
+/// Presentations depend on the true lottery result, so seeing one implies a win
+/// probability different from the odds of the lottery.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Reliability {
+    /// Probability that a draw shows the presentation.
+    pub frequency: f64,
+    /// Probability that a draw showing the presentation is a win, `None` if it is never shown.
+    pub win_rate: Option<f64>,
+}
+
+impl Reliability {
+    /// Builds the reliability from the probabilities of showing it with a win and with a loss.
+    fn new(win: f64, lose: f64) -> Self {
+        let frequency = win + lose;
+        Self {
+            frequency,
+            win_rate: (frequency > 0.0).then(|| win / frequency),
+        }
+    }
+}
+
+/// Reliabilities of the reels of [`SlotProducer::produce`](crate::slot::SlotProducer::produce),
+/// by what they show before a fake result is revealed.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FakeReliability {
+    /// Reels stopping on a win: `Win::Default`, or `Lose::FakeLose` before it slips.
+    pub win_shown: Reliability,
+    /// Reels stopping on a loss: `Lose::Default`, or `Win::FakeWin` before it revives.
+    pub lose_shown: Reliability,
+}
+
+/// Reliabilities of the reaches of [`SlotProducer::produce_outcome`](crate::slot::SlotProducer::produce_outcome).
+///
+/// [`OutcomeReliability`] splits them further by whether a fake result follows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReachReliability {
+    /// Reels stopping without a reach.
+    pub none: Reliability,
+    /// Normal reach.
+    pub normal: Reliability,
+    /// Super reach.
+    pub super_: Reliability,
+    /// Premium reach.
+    pub premium: Reliability,
+}
+
+/// Reliabilities of the presentations of [`SlotProducer::produce_outcome`](crate::slot::SlotProducer::produce_outcome),
+/// by the reach shown and whether a revival (or slip) follows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OutcomeReliability {
+    /// Presentations without a revival: `Win::Default` and `Lose::Default`.
+    pub plain: ReachReliability,
+    /// Presentations followed by a revival: `Win::FakeWin` and `Lose::FakeLose`.
+    pub revived: ReachReliability,
+}
+
+/// Computes the reliability of the reels shown before a fake result is revealed.
+///
+/// # Arguments
+///
+/// * `probability` - Probabilities of the lottery
+///
+/// # Returns
+///
+/// - `Ok(FakeReliability)` with the reliabilities
+/// - `Err(AnalysisError::Config)` if a probability is outside 0.0 to 1.0
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{analysis::fake_reliability, config::SlotProbability};
+///
+/// let probability = SlotProbability { win: 0.5, fake_win: 0.2, fake_lose: 0.1 };
+/// let reliability = fake_reliability(probability).unwrap();
+///
+/// // 0.5 * 0.8 of the draws are wins shown as such, 0.5 * 0.1 losses about to slip
+/// assert!((reliability.win_shown.frequency - 0.45).abs() < 1e-12);
+/// assert!((reliability.win_shown.win_rate.unwrap() - 0.4 / 0.45).abs() < 1e-12);
+/// ```
+pub fn fake_reliability(probability: SlotProbability) -> Result<FakeReliability, AnalysisError> {
+    probability.validate()?;
+
+    let SlotProbability {
+        win,
+        fake_win,
+        fake_lose,
+    } = probability;
+    let lose = 1.0 - win;
+    Ok(FakeReliability {
+        win_shown: Reliability::new(win * (1.0 - fake_win), lose * fake_lose),
+        lose_shown: Reliability::new(win * fake_win, lose * (1.0 - fake_lose)),
+    })
+}
+
+/// Computes the reliability of each reach kind, and of no reach.
+///
+/// Reaches are drawn independently of fake results, so only `probability.win` matters.
+/// See [`outcome_reliability`] for the reliability of a reach together with a revival.
+///
+/// # Arguments
+///
+/// * `probability` - Probabilities of the lottery
+/// * `reach` - Reach presentation of the slot producer
+///
+/// # Returns
+///
+/// - `Ok(ReachReliability)` with the reliabilities
+/// - `Err(AnalysisError::Config)` if a probability of the lottery is outside 0.0 to 1.0
+/// - `Err(AnalysisError::Unsupported)` if `reach` would be rejected by
+///   [`SlotProducer::with_reach`](crate::slot::SlotProducer::with_reach)
+pub fn reach_reliability(
+    probability: SlotProbability,
+    reach: ReachConfig,
+) -> Result<ReachReliability, AnalysisError> {
+    probability.validate()?;
+    validate_reach(reach)?;
+
+    Ok(reach_table(probability.win, 1.0 - probability.win, reach))
+}
+
+/// Computes the reliability of each presentation of a reach and a revival.
+///
+/// [`SlotProducer::produce_outcome`](crate::slot::SlotProducer::produce_outcome) shows
+/// the reach and the fake result of a draw together, so this is the joint table of
+/// both: the reach is drawn by whether the draw wins, the revival by the fake
+/// probabilities.
+///
+/// # Arguments
+///
+/// * `probability` - Probabilities of the lottery
+/// * `reach` - Reach presentation of the slot producer
+///
+/// # Returns
+///
+/// - `Ok(OutcomeReliability)` with the reliabilities
+/// - `Err(AnalysisError::Config)` if a probability of the lottery is outside 0.0 to 1.0
+/// - `Err(AnalysisError::Unsupported)` if `reach` would be rejected by
+///   [`SlotProducer::with_reach`](crate::slot::SlotProducer::with_reach)
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     analysis::outcome_reliability,
+///     config::SlotProbability,
+///     slot::{ReachConfig, ReachWeights},
+/// };
+///
+/// let probability = SlotProbability { win: 0.5, fake_win: 0.2, fake_lose: 0.1 };
+/// let kinds = ReachWeights { normal: 1, super_: 0, premium: 0 };
+/// let reach = ReachConfig { win: 1.0, lose: 0.5, win_kinds: kinds, lose_kinds: kinds };
+/// let reliability = outcome_reliability(probability, reach).unwrap();
+///
+/// // 0.5 * 0.2 of the draws are revived wins, 0.5 * 0.1 * 0.5 slipping reach losses
+/// assert!((reliability.revived.normal.frequency - 0.125).abs() < 1e-12);
+/// assert!((reliability.revived.normal.win_rate.unwrap() - 0.1 / 0.125).abs() < 1e-12);
+/// ```
+pub fn outcome_reliability(
+    probability: SlotProbability,
+    reach: ReachConfig,
+) -> Result<OutcomeReliability, AnalysisError> {
+    probability.validate()?;
+    validate_reach(reach)?;
+
+    let SlotProbability {
+        win,
+        fake_win,
+        fake_lose,
+    } = probability;
+    let lose = 1.0 - win;
+    Ok(OutcomeReliability {
+        plain: reach_table(win * (1.0 - fake_win), lose * (1.0 - fake_lose), reach),
+        revived: reach_table(win * fake_win, lose * fake_lose, reach),
+    })
+}
+
+/// Checks that `reach` would be accepted by `SlotProducer::with_reach`.
+fn validate_reach(reach: ReachConfig) -> Result<(), AnalysisError> {
+    for (chance, kinds) in [(reach.win, reach.win_kinds), (reach.lose, reach.lose_kinds)] {
+        if !(0.0..=1.0).contains(&chance) {
+            return Err(AnalysisError::Unsupported(
+                "reach probabilities must be between 0.0 and 1.0",
+            ));
+        }
+        if chance > 0.0 && kinds.total() == 0 {
+            return Err(AnalysisError::Unsupported(
+                "reach kinds must have a positive weight",
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Splits the probabilities `win` and `lose` of draws by the reach they are shown with.
+fn reach_table(win: f64, lose: f64, reach: ReachConfig) -> ReachReliability {
+    let kind = |weight: fn(&ReachWeights) -> u32| {
+        let share = |kinds: &ReachWeights| match kinds.total() {
+            0 => 0.0,
+            total => f64::from(weight(kinds)) / total as f64,
+        };
+        Reliability::new(
+            win * reach.win * share(&reach.win_kinds),
+            lose * reach.lose * share(&reach.lose_kinds),
+        )
+    };
+
+    ReachReliability {
+        none: Reliability::new(win * (1.0 - reach.win), lose * (1.0 - reach.lose)),
+        normal: kind(|kinds| kinds.normal),
+        super_: kind(|kinds| kinds.super_),
+        premium: kind(|kinds| kinds.premium),
+    }
+}
+
+/// Computes the reliability of each presentation stage.
+///
+/// A stage counts as shown whether or not later stages follow, so the reliability of
+/// a stage is the win rate of every draw reaching it. As in
+/// [`SlotProducer::produce_frames`](crate::slot::SlotProducer::produce_frames), the
+/// stages are drawn alike for default and fake results, so only `probability.win`
+/// matters. The frames added for fake results are not counted.
+///
+/// # Arguments
+///
+/// * `probability` - Probabilities of the lottery
+/// * `stages` - Presentation stages of the slot producer, in order
+///
+/// # Returns
+///
+/// - `Ok(Vec<Reliability>)` with the reliability of each stage, in the order of `stages`
+/// - `Err(AnalysisError::Config)` if a probability of the lottery is outside 0.0 to 1.0
+/// - `Err(AnalysisError::Unsupported)` if a probability of `stages` is outside 0.0 to 1.0
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     analysis::effect_reliability,
+///     config::SlotProbability,
+///     slot::{EffectKind, EffectStage},
+/// };
+///
+/// let probability = SlotProbability { win: 0.1, ..SlotProbability::default() };
+/// let stages = [
+///     EffectStage { kind: EffectKind::Reach, win: 1.0, lose: 0.2 },
+///     EffectStage { kind: EffectKind::SuperReach, win: 0.6, lose: 0.1 },
+/// ];
+/// let reliability = effect_reliability(probability, &stages).unwrap();
+///
+/// // 0.1 * 0.6 of the draws are super reach wins, 0.9 * 0.2 * 0.1 super reach losses
+/// assert!((reliability[1].win_rate.unwrap() - 0.06 / 0.078).abs() < 1e-12);
+/// ```
+pub fn effect_reliability(
+    probability: SlotProbability,
+    stages: &[EffectStage],
+) -> Result<Vec<Reliability>, AnalysisError> {
+    probability.validate()?;
+    if stages
+        .iter()
+        .any(|stage| !(0.0..=1.0).contains(&stage.win) || !(0.0..=1.0).contains(&stage.lose))
+    {
+        return Err(AnalysisError::Unsupported(
+            "effect probabilities must be between 0.0 and 1.0",
+        ));
+    }
+
+    let mut win = probability.win;
+    let mut lose = 1.0 - probability.win;
+    Ok(stages
+        .iter()
+        .map(|stage| {
+            win *= stage.win;
+            lose *= stage.lose;
+            Reliability::new(win, lose)
+        })
+        .collect())
+}
+
+/// Expected outcome of a single jackpot.
+#[derive(Debug, Clone, Copy)]
+struct Jackpot {
+    /// Net balls gained.
+    balls: f64,
+    /// Balls launched into the attacker during the bonus.
+    launches: f64,
+}
+
+impl Jackpot {
+    fn bonus(payout: &Payout, table: &[RoundWeight]) -> Self {
+        let total: f64 = table.iter().map(|entry| f64::from(entry.weight)).sum();
+        let rounds: f64 = table
+            .iter()
+            .map(|entry| entry.rounds as f64 * f64::from(entry.weight) / total)
+            .sum();
+        let launches = rounds * payout.counts as f64;
+        Self {
+            balls: launches * (payout.balls_per_count - 1) as f64,
+            launches,
+        }
+    }
+}
+
+/// Expected course of a single rush.
+struct Rush {
+    chain_distribution: Vec<f64>,
+    truncated: f64,
+    /// Expected jackpots won during the rush.
+    jackpots: f64,
+    /// Expected launches during the rush, excluding bonuses.
+    launches: f64,
+}
+
+/// Follows the distribution of remaining rush balls through the rush rounds.
+///
+/// In round `n` with `r` rush balls left, each of the next `r - 1` launches continues
+/// the rush with probability `continue_rate(n)`, adding `incremental_rush` balls; the
+/// `r`-th launch ends it. Rushes exceeding `MAX_RUSH_BALL_ROUNDS` rounds worth of balls
+/// are dropped into the truncated mass.
+fn continue_lottery_rush(
+    incremental_rush: usize,
+    win_rate: f64,
+    continue_rate: &mut impl FnMut(usize) -> f64,
+) -> Rush {
+    let b = incremental_rush;
+    let max_balls = MAX_RUSH_BALL_ROUNDS * b;
+    // remaining[r]: probability of entering the current round with r rush balls
+    let mut remaining = vec![0.0; b + 1];
+    remaining[b] = 1.0;
+
+    let mut chain_distribution = Vec::new();
+    let mut alive = 1.0;
+    let mut launches = 1.0; // the launch ending the rush
+    let mut jackpots = 0.0;
+
+    for n in 1..=MAX_CHAIN {
+        let c = continue_rate(n);
+        let miss = 1.0 - c;
+
+        let mut end = 0.0;
+        for (r, &p) in remaining.iter().enumerate() {
+            let draws = r.saturating_sub(1) as i32;
+            let stay = miss.powi(draws);
+            end += p * stay;
+            let expected_draws = if c > 0.0 {
+                (1.0 - stay) / c
+            } else {
+                draws as f64
+            };
+            launches += p * expected_draws;
+            jackpots += p * expected_draws * win_rate;
+        }
+        chain_distribution.push(end);
+        alive -= end;
+
+        if alive < TOLERANCE || c == 0.0 {
+            alive = alive.max(0.0);
+            break;
+        }
+
+        // Continuing at the k-th launch leads to r - k + b balls, for k in 1..r
+        let mut next = vec![0.0; remaining.len() + b];
+        let mut tail = 0.0;
+        for t in (2..remaining.len()).rev() {
+            tail = remaining[t] + miss * tail;
+            next[t - 1 + b] = c * tail;
+        }
+        while next.last().is_some_and(|&p| p < TOLERANCE * TOLERANCE) {
+            next.pop();
+        }
+        next.truncate(max_balls + 1);
+        remaining = next;
+
+        // Everything left has grown beyond the followed balls
+        if remaining.iter().sum::<f64>() < TOLERANCE {
+            break;
+        }
+    }
+
+    Rush {
+        chain_distribution,
+        truncated: alive,
+        jackpots,
+        launches,
+    }
+}
+
+/// ST rush: every round hits with a fixed probability within `spins` spins.
+fn special_times_rush(spins: usize, start_hole_probability: f64, win_rate: f64) -> Rush {
+    let stay = (1.0 - win_rate).powi(spins as i32);
+    let hit = 1.0 - stay;
+    let spins_per_round = if win_rate > 0.0 {
+        hit / win_rate
+    } else {
+        spins as f64
+    };
+
+    let mut chain_distribution = Vec::new();
+    let mut alive = 1.0;
+    while alive >= TOLERANCE && chain_distribution.len() < MAX_CHAIN {
+        chain_distribution.push(alive * stay);
+        alive *= hit;
+    }
+
+    let rounds = if stay > 0.0 {
+        1.0 / stay
+    } else {
+        f64::INFINITY
+    };
+    Rush {
+        chain_distribution,
+        truncated: alive,
+        jackpots: rounds - 1.0,
+        launches: rounds * spins_per_round / start_hole_probability,
+    }
+}
@@ -1,8 +1,10 @@
-use rand::{Rng, rngs::ThreadRng};
+use rand::{Rng, SeedableRng, rngs::ThreadRng};
 
 use crate::{
-    game::Game,
+    config::RushContinueFn,
+    game::{Game, GameError, GameState, UninitializedError},
     interface::{UserInput, UserOutput},
+    lottery::LotterySource,
 };
 
 /// Represents a command that can be executed within the game.
@@ -16,13 +18,13 @@ use crate::{
 /// * `I` - User input handler type implementing `UserInput<O>`
 /// * `O` - User output handler type implementing `UserOutput`
 /// * `F` - Function type for generating random numbers
-/// * `R` - Random number generator type implementing `Rng`
+/// * `R` - Source of lottery outcomes implementing `LotterySource`, e.g. any `Rng`
 pub enum Command<I, O, F = fn(usize) -> f64, R = ThreadRng>
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
     /// Command to finish the current game session.
     FinishGame,
@@ -34,8 +36,8 @@ impl<I, O, F, R> Command<I, O, F, R>
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
     /// Creates a new control command from any type implementing `ControlCommand`.
     ///
@@ -65,21 +67,86 @@ where
 ///
 /// * `I` - User input handler type implementing `UserInput<O>`
 /// * `O` - User output handler type implementing `UserOutput`
-/// * `F` - Function type for probability calculations implementing `FnMut(usize) -> f64`
-/// * `R` - Random number generator type implementing `Rng`
+/// * `F` - Function type for probability calculations implementing `RushContinueFn`
+/// * `R` - Source of lottery outcomes implementing `LotterySource`, e.g. any `Rng`
 pub trait ControlCommand<I, O, F = fn(usize) -> f64, R = ThreadRng>
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
     /// Executes the command, potentially modifying the game state.
     ///
     /// # Arguments
     ///
     /// * `game` - Mutable reference to the game instance to operate on
-    fn execute(&mut self, game: &mut Game<I, O, F, R>);
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the command was executed
+    /// - `Err(GameError)` if the command is not possible in the current game state
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError>;
+
+    /// Describes the command for a session log.
+    ///
+    /// # Returns
+    ///
+    /// The `RecordedCommand` replaying this command, or `None` (the default) if the
+    /// command cannot be recorded.
+    fn recorded(&self) -> Option<RecordedCommand> {
+        None
+    }
+}
+
+/// Built-in command as stored in a session log.
+///
+/// See [`Recorder`](crate::replay::Recorder) and [`Replayer`](crate::replay::Replayer).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RecordedCommand {
+    /// [`LaunchBall`] command.
+    LaunchBall,
+    /// [`CauseLottery`] command.
+    CauseLottery,
+    /// [`StartGame`] command.
+    StartGame,
+    /// [`FinishGame`] command.
+    FinishGame,
+    /// [`LaunchBallFlow`] command, with whether the ball entered the start hole.
+    LaunchBallFlow {
+        /// Whether the ball entered the start hole.
+        is_lottery: bool,
+    },
+    /// `Command::FinishGame`, ending the game loop.
+    Quit,
+}
+
+impl RecordedCommand {
+    /// Creates the command described by `self`.
+    ///
+    /// # Returns
+    ///
+    /// A `Command` that behaves like the recorded one.
+    pub fn into_command<I, O, F, R>(self) -> Command<I, O, F, R>
+    where
+        I: UserInput<O, F, R>,
+        O: UserOutput,
+        F: RushContinueFn,
+        R: LotterySource,
+    {
+        match self {
+            Self::LaunchBall => Command::control(LaunchBall),
+            Self::CauseLottery => Command::control(CauseLottery),
+            Self::StartGame => Command::control(StartGame),
+            Self::FinishGame => Command::control(FinishGame),
+            Self::LaunchBallFlow { is_lottery } => {
+                Command::control(LaunchBallFlow::new(is_lottery))
+            }
+            Self::Quit => Command::FinishGame,
+        }
+    }
 }
 
 /// Command to launch a single ball in the game.
@@ -92,11 +159,16 @@ impl<I, O, F, R> ControlCommand<I, O, F, R> for LaunchBall
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
-    fn execute(&mut self, game: &mut Game<I, O, F, R>) {
-        let _ = game.launch_ball();
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError> {
+        game.launch_ball()?;
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::LaunchBall)
     }
 }
 
@@ -104,18 +176,26 @@ where
 ///
 /// This command initiates the lottery system based on the current game mode
 /// (normal or rush) and handles the resulting outcomes including potential
-/// rush mode transitions.
+/// rush mode transitions. The lottery is drawn immediately, regardless of the hold.
 pub struct CauseLottery;
 
 impl<I, O, F, R> ControlCommand<I, O, F, R> for CauseLottery
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
-    fn execute(&mut self, game: &mut Game<I, O, F, R>) {
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError> {
+        if *game.state() == GameState::Uninitialized {
+            return Err(UninitializedError.into());
+        }
         game.cause_lottery();
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::CauseLottery)
     }
 }
 
@@ -129,11 +209,16 @@ impl<I, O, F, R> ControlCommand<I, O, F, R> for StartGame
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
-    fn execute(&mut self, game: &mut Game<I, O, F, R>) {
-        let _ = game.start();
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError> {
+        game.start()?;
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::StartGame)
     }
 }
 
@@ -147,11 +232,16 @@ impl<I, O, F, R> ControlCommand<I, O, F, R> for FinishGame
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
-    fn execute(&mut self, game: &mut Game<I, O, F, R>) {
-        let _ = game.finish();
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError> {
+        game.finish()?;
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::FinishGame)
     }
 }
 
@@ -159,14 +249,18 @@ where
 ///
 /// This struct manages the probability of whether a launched ball will trigger
 /// a lottery event, simulating the physical behavior of balls entering special holes.
-pub struct LaunchBallFlowProducer {
+///
+/// # Type Parameters
+///
+/// * `R` - Random number generator type implementing `Rng` (defaults to `ThreadRng`)
+pub struct LaunchBallFlowProducer<R: Rng = ThreadRng> {
     /// Probability that a launched ball will trigger a lottery (0.0 to 1.0).
     start_hole_probability: f64,
     /// Random number generator for probability calculations.
-    rng: ThreadRng,
+    rng: R,
 }
 
-impl LaunchBallFlowProducer {
+impl<R: Rng + Default> LaunchBallFlowProducer<R> {
     /// Creates a new ball launch flow producer with the specified lottery probability.
     ///
     /// # Arguments
@@ -177,9 +271,41 @@ impl LaunchBallFlowProducer {
     ///
     /// A new `LaunchBallFlowProducer` instance.
     pub fn new(start_hole_probability: f64) -> Self {
+        Self::with_rng(start_hole_probability, R::default())
+    }
+}
+
+impl<R: Rng + SeedableRng> LaunchBallFlowProducer<R> {
+    /// Creates a new ball launch flow producer whose RNG is seeded with `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_hole_probability` - Probability (0.0 to 1.0) that a ball will trigger lottery
+    /// * `seed` - Seed for the start hole random number generator
+    ///
+    /// # Returns
+    ///
+    /// A new `LaunchBallFlowProducer` instance producing a reproducible sequence.
+    pub fn from_seed(start_hole_probability: f64, seed: u64) -> Self {
+        Self::with_rng(start_hole_probability, R::seed_from_u64(seed))
+    }
+}
+
+impl<R: Rng> LaunchBallFlowProducer<R> {
+    /// Creates a new ball launch flow producer with a custom random number generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `start_hole_probability` - Probability (0.0 to 1.0) that a ball will trigger lottery
+    /// * `rng` - Custom random number generator implementing `Rng`
+    ///
+    /// # Returns
+    ///
+    /// A new `LaunchBallFlowProducer` instance using the provided RNG.
+    pub fn with_rng(start_hole_probability: f64, rng: R) -> Self {
         Self {
             start_hole_probability,
-            rng: rand::rng(),
+            rng,
         }
     }
 
@@ -198,8 +324,11 @@ impl LaunchBallFlowProducer {
 
 /// Command that represents a complete ball launch flow.
 ///
-/// This command combines ball launching with optional lottery triggering,
+/// This command combines ball launching with an optional start hole entry,
 /// simulating the complete sequence of events when a ball is launched in the game.
+/// The entry is drawn immediately or held, depending on the spin clock. During
+/// jitan, a ball that missed the start hole may still be guided into it by the
+/// electric tulip.
 pub struct LaunchBallFlow {
     /// Whether this ball launch should trigger a lottery event.
     is_lottery: bool,
@@ -224,14 +353,22 @@ impl<I, O, F, R> ControlCommand<I, O, F, R> for LaunchBallFlow
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
+    F: RushContinueFn,
+    R: LotterySource,
 {
-    fn execute(&mut self, game: &mut Game<I, O, F, R>) {
-        let _ = game.launch_ball();
-
-        if self.is_lottery {
-            game.cause_lottery();
+    fn execute(&mut self, game: &mut Game<I, O, F, R>) -> Result<(), GameError> {
+        game.launch_ball()?;
+        // The last ball ends the game before it can reach the start hole
+        let running = *game.state() != GameState::Uninitialized;
+        if running && (self.is_lottery || game.assist_start_hole()) {
+            game.enter_start_hole()?;
         }
+        Ok(())
+    }
+
+    fn recorded(&self) -> Option<RecordedCommand> {
+        Some(RecordedCommand::LaunchBallFlow {
+            is_lottery: self.is_lottery,
+        })
     }
 }
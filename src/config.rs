@@ -1,5 +1,7 @@
 use std::{error::Error, fmt::Display};
 
+use crate::odds::{Odds, ParseOddsError};
+
 /// Main configuration structure for the pachislot game.
 ///
 /// This structure contains all the necessary settings to configure game behavior,
@@ -8,33 +10,59 @@ use std::{error::Error, fmt::Display};
 /// # Examples
 ///
 /// ```ignore
-/// use pachislo::config::{Config, BallsConfig, Probability, SlotProbability};
+/// use pachislo::config::{
+///     BallsConfig, Config, DEFAULT_JITAN_START_HOLE, Probability, RushModel, SlotProbability,
+/// };
 ///
 /// let config = Config {
 ///     balls: BallsConfig {
 ///         init_balls: 1000,
 ///         incremental_balls: 15,
 ///         incremental_rush: 300,
+///         hold_capacity: 4,
+///         spin_duration: 0,
+///         jitan_spins: 100,
+///         jitan_spin_duration: 0,
+///         rush_model: RushModel::ContinueLottery,
+///         ceiling: None,
 ///     },
 ///     probability: Probability {
 ///         normal: SlotProbability { win: 0.16, fake_win: 0.3, fake_lose: 0.15 },
 ///         rush: SlotProbability { win: 0.48, fake_win: 0.2, fake_lose: 0.05 },
 ///         rush_continue: SlotProbability { win: 0.8, fake_win: 0.25, fake_lose: 0.1 },
 ///         rush_continue_fn: |n| 0.6_f64.powi(n as i32 - 1),
+///         rush_continue_horizon: 100,
+///         kakuhen: SlotProbability { win: 0.4, fake_win: 0.3, fake_lose: 0.15 },
+///         kakuhen_entry: 0.0,
+///         kakuhen_fall: 0.0,
+///         jitan_start_hole: DEFAULT_JITAN_START_HOLE,
+///         rush_entry: 1.0,
 ///     },
+///     payout: None,
 /// };
 /// ```
-pub struct Config<F: FnMut(usize) -> f64 = fn(usize) -> f64> {
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Config<F: RushContinueFn = fn(usize) -> f64> {
     /// Configuration for ball counts and increments.
     pub balls: BallsConfig,
     /// Configuration for lottery probabilities in different game modes.
     pub probability: Probability<F>,
+    /// Jackpot round/payout structure.
+    ///
+    /// With `None` a jackpot adds `BallsConfig::incremental_balls` in one step.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub payout: Option<Payout>,
 }
 
 /// Configuration for ball counts and increments in the game.
 ///
 /// This structure defines how many balls the player starts with and how many
 /// they receive for various game events.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct BallsConfig {
     /// Initial number of balls when starting a new game.
     ///
@@ -48,34 +76,236 @@ pub struct BallsConfig {
     ///
     /// These are special "rush balls" that are consumed during rush mode play.
     pub incremental_rush: usize,
+    /// Maximum number of start hole entries that can wait for a spin (hold / 保留).
+    ///
+    /// Entries arriving while the reels are spinning are stored up to this capacity;
+    /// further entries are counted as wasted.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub hold_capacity: usize,
+    /// Number of ball launches one spin of the reels lasts.
+    ///
+    /// A held entry is drawn when the current spin stops. With 0 the reels stop
+    /// immediately, so every entry is drawn at once and the hold is never used.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub spin_duration: usize,
+    /// Number of jitan (time-shortening) spins granted when rush mode ends.
+    ///
+    /// During jitan the jackpot odds are those of normal mode, but spins are shorter
+    /// and the electric tulip helps balls into the start hole. With 0 rush mode
+    /// returns straight to normal mode.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub jitan_spins: usize,
+    /// Number of ball launches one spin lasts during jitan, replacing `spin_duration`.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub jitan_spin_duration: usize,
+    /// How long a rush lasts and how it continues.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub rush_model: RushModel,
+    /// Ceiling (天井) forcing a mode after too many consecutive losing spins in normal mode.
+    ///
+    /// With `None` there is no ceiling.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ceiling: Option<Ceiling>,
+}
+
+/// Ceiling (天井) that rescues a player after a long losing streak.
+///
+/// Once `spins` consecutive spins in normal mode have lost, the ceiling fires and
+/// forces the mode given by `reward`, without paying out a jackpot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Ceiling {
+    /// Number of consecutive losing spins in normal mode that fires the ceiling.
+    pub spins: usize,
+    /// Mode forced when the ceiling fires.
+    pub reward: CeilingReward,
+}
+
+/// Mode forced when the ceiling fires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum CeilingReward {
+    /// Jitan mode for the given number of spins.
+    Jitan {
+        /// Number of jitan spins granted.
+        spins: usize,
+    },
+    /// Rush mode, entered as if a jackpot had been routed into rush.
+    Rush,
+}
+
+/// Model deciding how long a rush lasts and how it continues.
+///
+/// Both models share the rush lottery (`Probability::rush`), so "continue-lottery"
+/// and "ST" machine specs can be compared with the same engine.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum RushModel {
+    /// Rush lasts for a budget of rush balls (`incremental_rush` per round).
+    ///
+    /// Every rush win draws a separate continuation lottery weighted by
+    /// `rush_continue_fn(n)`; a successful continuation adds another budget.
+    #[default]
+    ContinueLottery,
+    /// ST (special times) rush lasting a fixed number of spins.
+    ///
+    /// The rush continues only if a jackpot hits within the remaining spins, which
+    /// then resets them. `rush_continue` and `rush_continue_fn` are not used.
+    SpecialTimes {
+        /// Number of spins granted on entering rush and after every rush jackpot.
+        spins: usize,
+    },
+}
+
+/// Jackpot payout structure.
+///
+/// A jackpot consists of a number of rounds, drawn from a weighted round table.
+/// In each round the attacker (大入賞口) opens for up to `counts` balls and pays
+/// `balls_per_count` balls for each of them. While the rounds are played the game
+/// is in a bonus phase, during which launched balls feed the attacker.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::config::{Payout, RoundWeight};
+///
+/// // 4R or 10R in normal mode, always 10R in rush
+/// let payout = Payout {
+///     normal: vec![
+///         RoundWeight { rounds: 4, weight: 1 },
+///         RoundWeight { rounds: 10, weight: 1 },
+///     ],
+///     rush: vec![RoundWeight { rounds: 10, weight: 1 }],
+///     counts: 10,
+///     balls_per_count: 15,
+/// };
+/// assert_eq!(payout.round_balls(), 150);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Payout {
+    /// Round table for jackpots won outside rush (Normal, Kakuhen and Jitan mode).
+    pub normal: Vec<RoundWeight>,
+    /// Round table for jackpots won in rush (Rush and SpecialTimes mode).
+    pub rush: Vec<RoundWeight>,
+    /// Maximum number of balls entering the attacker in one round.
+    pub counts: usize,
+    /// Number of balls paid out for every ball entering the attacker.
+    pub balls_per_count: usize,
+}
+
+/// One entry of a weighted round table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct RoundWeight {
+    /// Number of rounds of the jackpot.
+    pub rounds: usize,
+    /// Relative weight of this entry within its table.
+    pub weight: u32,
+}
+
+impl Payout {
+    /// Returns the number of balls paid out by one full round.
+    pub fn round_balls(&self) -> usize {
+        self.counts * self.balls_per_count
+    }
 }
 
 /// Probability configuration for slot machine outcomes.
 ///
 /// This structure defines the probabilities for different types of lottery results,
 /// including real wins/losses and fake (visual effect) outcomes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct SlotProbability {
     /// Base probability of winning (0.0 to 1.0).
     ///
     /// This is the fundamental chance of a positive lottery outcome.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::odds::deserialize_probability")
+    )]
     pub win: f64,
     /// Probability of showing a fake win animation after a real win (0.0 to 1.0).
     ///
     /// Creates suspense by initially showing a losing result before revealing the actual win.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::odds::deserialize_probability")
+    )]
     pub fake_win: f64,
     /// Probability of showing a fake lose animation after a real loss (0.0 to 1.0).
     ///
     /// Creates false hope by initially showing a winning result before revealing the actual loss.
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "crate::odds::deserialize_probability")
+    )]
     pub fake_lose: f64,
 }
 
+impl SlotProbability {
+    /// Creates a probability set from the odds of winning, without fake effects.
+    ///
+    /// # Arguments
+    ///
+    /// * `win` - Odds of winning, e.g. `"1/319.7"`, `"0.5%"` or `"0.003"`
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SlotProbability)` with `fake_win` and `fake_lose` set to 0.0
+    /// - `Err(ParseOddsError)` if `win` is not valid odds notation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pachislo::config::SlotProbability;
+    ///
+    /// let normal = SlotProbability {
+    ///     fake_win: 0.3,
+    ///     ..SlotProbability::from_odds("1/319.7").unwrap()
+    /// };
+    /// assert_eq!(normal.win, 1.0 / 319.7);
+    /// ```
+    pub fn from_odds(win: &str) -> Result<Self, ParseOddsError> {
+        Ok(Self {
+            win: win.parse::<Odds>()?.0,
+            ..Self::default()
+        })
+    }
+
+    /// Returns the odds of winning.
+    pub fn win_odds(&self) -> Odds {
+        Odds(self.win)
+    }
+}
+
+impl Display for SlotProbability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "win {}, fake win {:.2}%, fake lose {:.2}%",
+            self.win_odds(),
+            self.fake_win * 100.0,
+            self.fake_lose * 100.0
+        )
+    }
+}
+
 /// Comprehensive probability configuration for all game modes.
 ///
 /// This structure contains probability settings for different game states and the
 /// mathematical function that controls rush mode continuation decay.
 #[derive(Debug, Clone, Copy)]
-pub struct Probability<F: FnMut(usize) -> f64 = fn(usize) -> f64> {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Probability<F: RushContinueFn = fn(usize) -> f64> {
     /// Probability settings for normal (standard) game mode.
     pub normal: SlotProbability,
     /// Probability settings for rush (bonus) game mode.
@@ -99,14 +329,166 @@ pub struct Probability<F: FnMut(usize) -> f64 = fn(usize) -> f64> {
     /// # Returns
     ///
     /// A multiplier value that should be between 0.0 and 1.0.
+    ///
+    /// Any closure works here; use a [`ContinueCurve`](crate::curve::ContinueCurve)
+    /// when the configuration needs to be stored or displayed.
     pub rush_continue_fn: F,
+    /// Number of rush rounds over which `rush_continue_fn` is checked when the
+    /// configuration is validated.
+    ///
+    /// Validation evaluates the function for `n = 1..=rush_continue_horizon` and
+    /// rejects it unless it returns 1.0 for `n = 1`, stays within 0.0 to 1.0 and is
+    /// monotonically non-increasing. Stateful closures are therefore called before
+    /// the game starts.
+    ///
+    /// Defaults to [`DEFAULT_RUSH_CONTINUE_HORIZON`].
+    #[cfg_attr(feature = "serde", serde(default = "default_rush_continue_horizon"))]
+    pub rush_continue_horizon: usize,
+    /// Probability settings for kakuhen (probability variable) mode.
+    ///
+    /// Kakuhen keeps the player's own balls in play like normal mode, but with
+    /// boosted jackpot odds until the next jackpot.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub kakuhen: SlotProbability,
+    /// Share of jackpots (0.0 to 1.0) that are routed into kakuhen mode instead of rush mode.
+    ///
+    /// Applies to jackpots won in normal and kakuhen mode, so a kakuhen jackpot can
+    /// route into kakuhen again. With 0.0 kakuhen mode is never entered.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "crate::odds::deserialize_probability")
+    )]
+    pub kakuhen_entry: f64,
+    /// Probability (0.0 to 1.0) that a losing kakuhen spin falls back to normal mode (転落).
+    ///
+    /// With 0.0 kakuhen mode only ends with the next jackpot.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, deserialize_with = "crate::odds::deserialize_probability")
+    )]
+    pub kakuhen_fall: f64,
+    /// Probability (0.0 to 1.0) that the electric tulip guides a ball into the start hole
+    /// during jitan when it would otherwise have missed.
+    ///
+    /// Defaults to [`DEFAULT_JITAN_START_HOLE`].
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_jitan_start_hole",
+            deserialize_with = "crate::odds::deserialize_probability"
+        )
+    )]
+    pub jitan_start_hole: f64,
+    /// Probability (0.0 to 1.0) that a jackpot won outside rush enters rush mode (RUSH突入率).
+    ///
+    /// A jackpot that misses the rush is still paid out and continues like a finished
+    /// rush: in jitan mode for `jitan_spins` spins, or in normal mode. Jackpots routed
+    /// into kakuhen mode are not affected.
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            default = "default_rush_entry",
+            deserialize_with = "crate::odds::deserialize_probability"
+        )
+    )]
+    pub rush_entry: f64,
+}
+
+/// Number of rush rounds over which `rush_continue_fn` is validated unless configured otherwise.
+pub const DEFAULT_RUSH_CONTINUE_HORIZON: usize = 100;
+
+#[cfg(feature = "serde")]
+fn default_rush_continue_horizon() -> usize {
+    DEFAULT_RUSH_CONTINUE_HORIZON
+}
+
+/// Probability of the electric tulip assist during jitan unless configured otherwise.
+pub const DEFAULT_JITAN_START_HOLE: f64 = 0.5;
+
+#[cfg(feature = "serde")]
+fn default_jitan_start_hole() -> f64 {
+    DEFAULT_JITAN_START_HOLE
+}
+
+impl<F: RushContinueFn> Probability<F> {
+    /// Creates a probability configuration from the lotteries every machine has.
+    ///
+    /// The other fields take their defaults: `rush_continue_horizon` is
+    /// [`DEFAULT_RUSH_CONTINUE_HORIZON`], `jitan_start_hole` is
+    /// [`DEFAULT_JITAN_START_HOLE`], kakuhen mode is disabled and every jackpot enters
+    /// rush mode (`rush_entry` 1.0). Override them
+    /// with struct update syntax, so code keeps compiling when fields are added.
+    ///
+    /// # Arguments
+    ///
+    /// * `normal` - Probabilities of normal mode
+    /// * `rush` - Probabilities of rush mode
+    /// * `rush_continue` - Base probabilities of the rush continuation lottery
+    /// * `rush_continue_fn` - Multiplier of the rush continuation probability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pachislo::config::{Probability, SlotProbability};
+    ///
+    /// let probability = Probability {
+    ///     rush_entry: 0.5,
+    ///     ..Probability::new(
+    ///         SlotProbability::from_odds("1/319.7").unwrap(),
+    ///         SlotProbability::from_odds("1/99.9").unwrap(),
+    ///         SlotProbability { win: 0.8, ..SlotProbability::default() },
+    ///         |n: usize| 0.6_f64.powi(n as i32 - 1),
+    ///     )
+    /// };
+    /// assert_eq!(probability.kakuhen_entry, 0.0);
+    /// ```
+    pub fn new(
+        normal: SlotProbability,
+        rush: SlotProbability,
+        rush_continue: SlotProbability,
+        rush_continue_fn: F,
+    ) -> Self {
+        Self {
+            normal,
+            rush,
+            rush_continue,
+            rush_continue_fn,
+            rush_continue_horizon: DEFAULT_RUSH_CONTINUE_HORIZON,
+            kakuhen: SlotProbability::default(),
+            kakuhen_entry: 0.0,
+            kakuhen_fall: 0.0,
+            jitan_start_hole: DEFAULT_JITAN_START_HOLE,
+            rush_entry: 1.0,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn default_rush_entry() -> f64 {
+    1.0
+}
+
+/// Function that calculates the multiplier for rush continuation probability.
+///
+/// This is implemented for every closure or function pointer of type
+/// `FnMut(usize) -> f64`, and for the declarative [`ContinueCurve`](crate::curve::ContinueCurve), which
+/// can additionally be serialized, compared and displayed.
+pub trait RushContinueFn {
+    /// Returns the multiplier for the `n`-th consecutive rush round (starting from 1).
+    fn evaluate(&mut self, n: usize) -> f64;
+}
+
+impl<F: FnMut(usize) -> f64> RushContinueFn for F {
+    fn evaluate(&mut self, n: usize) -> f64 {
+        self(n)
+    }
 }
 
 /// Error type for configuration validation failures.
 ///
 /// This error accumulates all validation issues found in a configuration,
 /// allowing users to see all problems at once rather than fixing them one by one.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ConfigError {
     /// List of error messages describing validation failures.
     errors: Vec<String>,
@@ -125,8 +507,23 @@ impl ConfigError {
         Self { errors: Vec::new() }
     }
 
-    pub(crate) fn append(&mut self, error: &mut ConfigError) {
-        self.errors.append(&mut error.errors);
+    /// Returns every validation message, each prefixed with the path of the offending field.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// for message in error.errors() {
+    ///     eprintln!("{message}"); // e.g. "probability.normal.win: probability must be between 0.0 and 1.0"
+    /// }
+    /// ```
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Moves all errors of a nested configuration into `self`, prefixing them with `path`.
+    pub(crate) fn append_at(&mut self, path: &str, error: &mut ConfigError) {
+        self.errors
+            .extend(error.errors.drain(..).map(|e| format!("{path}.{e}")));
     }
 
     pub(crate) fn push(&mut self, error: String) {
@@ -138,56 +535,186 @@ impl ConfigError {
     }
 }
 
-impl<F: FnMut(usize) -> f64> Config<F> {
-    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+impl<F: RushContinueFn> Config<F> {
+    pub(crate) fn validate(&mut self) -> Result<(), ConfigError> {
         let mut error = ConfigError::new();
         if let Err(mut err) = self.balls.validate() {
-            error.append(&mut err);
+            error.append_at("balls", &mut err);
         }
         if let Err(mut err) = self.probability.validate() {
-            error.append(&mut err);
+            error.append_at("probability", &mut err);
+        }
+        if let Some(Err(mut err)) = self.payout.as_ref().map(Payout::validate) {
+            error.append_at("payout", &mut err);
         }
         if error.is_empty() { Ok(()) } else { Err(error) }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<F> Config<F>
+where
+    F: RushContinueFn + serde::de::DeserializeOwned,
+{
+    /// Loads and validates a configuration from a TOML document.
+    ///
+    /// The document mirrors the structure of [`Config`]: a `[balls]` table and a
+    /// `[probability]` table with `normal`, `rush` and `rush_continue` sub-tables.
+    /// Probabilities may be numbers or [`Odds`] strings such as `"1/319.7"` or `"50%"`.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Config)` if the document parses and passes validation
+    /// - `Err(ConfigError)` with the parse error, or with every validation error
+    ///   prefixed by its field path (e.g. `probability.rush.win`)
+    pub fn from_toml_str(s: &str) -> Result<Self, ConfigError> {
+        let mut config: Self = toml::from_str(s).map_err(|e| ConfigError {
+            errors: vec![e.to_string()],
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Loads and validates a configuration from a JSON document.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Config)` if the document parses and passes validation
+    /// - `Err(ConfigError)` with the parse error, or with every validation error
+    ///   prefixed by its field path (e.g. `probability.rush.win`)
+    pub fn from_json_str(s: &str) -> Result<Self, ConfigError> {
+        let mut config: Self = serde_json::from_str(s).map_err(|e| ConfigError {
+            errors: vec![e.to_string()],
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+}
+
 impl BallsConfig {
     pub(crate) fn validate(&self) -> Result<(), ConfigError> {
         let mut error = ConfigError::new();
         if self.init_balls < 1 {
-            error.push("initial balls must be greater than 0".to_string());
+            error.push("init_balls: initial balls must be greater than 0".to_string());
+        }
+        if self.rush_model == (RushModel::SpecialTimes { spins: 0 }) {
+            error.push("rush_model.spins: must be greater than 0".to_string());
+        }
+        if let Some(ceiling) = self.ceiling {
+            if ceiling.spins < 1 {
+                error.push("ceiling.spins: must be greater than 0".to_string());
+            }
+            if ceiling.reward == (CeilingReward::Jitan { spins: 0 }) {
+                error.push("ceiling.reward.spins: must be greater than 0".to_string());
+            }
         }
         if error.is_empty() { Ok(()) } else { Err(error) }
     }
 }
 
-impl<F: FnMut(usize) -> f64> Probability<F> {
-    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+impl<F: RushContinueFn> Probability<F> {
+    pub(crate) fn validate(&mut self) -> Result<(), ConfigError> {
         let mut error = ConfigError::new();
         if let Err(mut err) = self.normal.validate() {
-            error.append(&mut err);
+            error.append_at("normal", &mut err);
         }
         if let Err(mut err) = self.rush.validate() {
-            error.append(&mut err);
+            error.append_at("rush", &mut err);
         }
         if let Err(mut err) = self.rush_continue.validate() {
-            error.append(&mut err);
+            error.append_at("rush_continue", &mut err);
+        }
+        if let Err(mut err) = self.kakuhen.validate() {
+            error.append_at("kakuhen", &mut err);
         }
+        if !(0.0..=1.0).contains(&self.kakuhen_entry) {
+            error.push("kakuhen_entry: probability must be between 0.0 and 1.0".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.kakuhen_fall) {
+            error.push("kakuhen_fall: probability must be between 0.0 and 1.0".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.jitan_start_hole) {
+            error.push("jitan_start_hole: probability must be between 0.0 and 1.0".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.rush_entry) {
+            error.push("rush_entry: probability must be between 0.0 and 1.0".to_string());
+        }
+        self.validate_rush_continue_fn(&mut error);
         if error.is_empty() { Ok(()) } else { Err(error) }
     }
+
+    fn validate_rush_continue_fn(&mut self, error: &mut ConfigError) {
+        if self.rush_continue_horizon < 1 {
+            error.push("rush_continue_horizon: must be greater than 0".to_string());
+            return;
+        }
+
+        let values: Vec<f64> = (1..=self.rush_continue_horizon)
+            .map(|n| self.rush_continue_fn.evaluate(n))
+            .collect();
+
+        if values[0] != 1.0 {
+            error.push(format!(
+                "rush_continue_fn: must return 1.0 for n = 1 (got {})",
+                values[0]
+            ));
+        }
+        if let Some(i) = values.iter().position(|v| !(0.0..=1.0).contains(v)) {
+            error.push(format!(
+                "rush_continue_fn: must be between 0.0 and 1.0 (got {} for n = {})",
+                values[i],
+                i + 1
+            ));
+        }
+        if let Some(i) = values.windows(2).position(|w| w[1] > w[0]) {
+            error.push(format!(
+                "rush_continue_fn: must be monotonically non-increasing (got {} for n = {} and {} for n = {})",
+                values[i],
+                i + 1,
+                values[i + 1],
+                i + 2
+            ));
+        }
+    }
+}
+
+impl Payout {
+    pub(crate) fn validate(&self) -> Result<(), ConfigError> {
+        let mut error = ConfigError::new();
+        Self::validate_table("normal", &self.normal, &mut error);
+        Self::validate_table("rush", &self.rush, &mut error);
+        if self.counts < 1 {
+            error.push("counts: must be greater than 0".to_string());
+        }
+        if self.balls_per_count < 1 {
+            error.push("balls_per_count: must be greater than 0".to_string());
+        }
+        if error.is_empty() { Ok(()) } else { Err(error) }
+    }
+
+    fn validate_table(name: &str, table: &[RoundWeight], error: &mut ConfigError) {
+        if table.iter().all(|entry| entry.weight == 0) {
+            error.push(format!(
+                "{name}: round table must contain an entry with a positive weight"
+            ));
+        }
+        if table.iter().any(|entry| entry.rounds == 0) {
+            error.push(format!("{name}.rounds: must be greater than 0"));
+        }
+    }
 }
 
 impl SlotProbability {
     pub(crate) fn validate(&self) -> Result<(), ConfigError> {
         let mut error = ConfigError::new();
-        if self.win < 0.0 || self.win > 1.0 {
-            error.push("win probability must be between 0.0 and 1.0".to_string());
+        if !(0.0..=1.0).contains(&self.win) {
+            error.push("win: probability must be between 0.0 and 1.0".to_string());
         }
-        if self.fake_win < 0.0 || self.fake_win > 1.0 {
-            error.push("fake_win probability must be between 0.0 and 1.0".to_string());
+        if !(0.0..=1.0).contains(&self.fake_win) {
+            error.push("fake_win: probability must be between 0.0 and 1.0".to_string());
         }
-        if self.fake_lose < 0.0 || self.fake_lose > 1.0 {
-            error.push("fake_lose probability must be between 0.0 and 1.0".to_string());
+        if !(0.0..=1.0).contains(&self.fake_lose) {
+            error.push("fake_lose: probability must be between 0.0 and 1.0".to_string());
         }
         if error.is_empty() { Ok(()) } else { Err(error) }
     }
@@ -0,0 +1,109 @@
+use std::fmt::Display;
+
+use crate::config::RushContinueFn;
+
+/// Declarative shape of the rush continuation multiplier.
+///
+/// Unlike an arbitrary closure, a `ContinueCurve` can be serialized, compared and
+/// displayed, so machine specs using it can be stored in files and printed in reports.
+/// It implements [`RushContinueFn`] and can therefore be used as `F` in `Config<F>`.
+///
+/// Every variant returns 1.0 for `n == 1` as long as its parameters are sensible
+/// (e.g. the first table entry is 1.0).
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::curve::ContinueCurve;
+///
+/// let curve = ContinueCurve::Geometric { ratio: 0.6 };
+/// assert_eq!(curve.value(1), 1.0);
+/// assert_eq!(curve.value(3), 0.36);
+/// assert_eq!(curve.to_string(), "0.6^(n-1)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ContinueCurve {
+    /// No decay: the multiplier is always 1.0.
+    Constant,
+    /// Multiplier decays geometrically: `ratio^(n-1)`.
+    Geometric {
+        /// Factor applied for every additional rush round.
+        ratio: f64,
+    },
+    /// Multiplier decreases linearly and is clamped from below: `max(1 - step * (n-1), floor)`.
+    Linear {
+        /// Amount subtracted for every additional rush round.
+        step: f64,
+        /// Lowest multiplier the curve ever returns.
+        floor: f64,
+    },
+    /// Explicit multipliers for `n = 1, 2, ...`; the last entry repeats for larger `n`.
+    Table(Vec<f64>),
+    /// Piecewise constant multiplier.
+    ///
+    /// Each `(from, value)` pair applies `value` from the `from`-th rush round onwards,
+    /// until the next threshold. Rounds before the first threshold use 1.0.
+    /// Thresholds must be sorted by `from` in ascending order.
+    Step {
+        /// `(from, value)` pairs sorted by `from`.
+        thresholds: Vec<(usize, f64)>,
+    },
+}
+
+impl ContinueCurve {
+    /// Returns the multiplier for the `n`-th consecutive rush round (starting from 1).
+    pub fn value(&self, n: usize) -> f64 {
+        let k = n.saturating_sub(1);
+        match self {
+            Self::Constant => 1.0,
+            Self::Geometric { ratio } => ratio.powi(k as i32),
+            Self::Linear { step, floor } => (1.0 - step * k as f64).max(*floor),
+            Self::Table(values) => values.get(k).or(values.last()).copied().unwrap_or(1.0),
+            Self::Step { thresholds } => thresholds
+                .iter()
+                .take_while(|(from, _)| *from <= n)
+                .last()
+                .map_or(1.0, |(_, value)| *value),
+        }
+    }
+
+    /// Returns the multipliers for `n = 1..=horizon`, e.g. for printing in reports.
+    pub fn values(&self, horizon: usize) -> Vec<f64> {
+        (1..=horizon).map(|n| self.value(n)).collect()
+    }
+}
+
+impl RushContinueFn for ContinueCurve {
+    fn evaluate(&mut self, n: usize) -> f64 {
+        self.value(n)
+    }
+}
+
+impl Display for ContinueCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Constant => write!(f, "1"),
+            Self::Geometric { ratio } => write!(f, "{ratio}^(n-1)"),
+            Self::Linear { step, floor } => write!(f, "max(1 - {step}(n-1), {floor})"),
+            Self::Table(values) => {
+                write!(f, "[")?;
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                write!(f, "]")
+            }
+            Self::Step { thresholds } => {
+                write!(f, "1")?;
+                for (from, value) in thresholds {
+                    write!(f, "; {value} (n >= {from})")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
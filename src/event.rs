@@ -0,0 +1,172 @@
+use crate::{
+    config::Ceiling,
+    game::{Bonus, GameError, GameState, Hold, Transition},
+    interface::UserOutput,
+    lottery::{JackpotCategory, LotteryResult, ProbabilityError},
+};
+
+/// Lottery kinds a spin can draw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LotteryMode {
+    /// Jackpot lottery with normal odds (Normal and Jitan mode).
+    Normal,
+    /// Jackpot lottery with rush odds (Rush and SpecialTimes mode).
+    Rush,
+    /// Continuation lottery after a win in Rush mode.
+    RushContinue,
+    /// Jackpot lottery with kakuhen odds.
+    Kakuhen,
+}
+
+/// Something that happened in a [`Game`](crate::Game), in the order it happened.
+///
+/// Events are delivered to [`UserOutput::event`] as they occur, so front-ends do not
+/// have to diff [`Transition`]s to find out what changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameEvent {
+    /// A game session started with `balls` balls.
+    GameStarted {
+        /// Number of balls at the start.
+        balls: usize,
+    },
+    /// The game session ended, either finished by the player or because the balls ran out.
+    GameFinished {
+        /// Number of balls left (0 when the balls ran out).
+        balls: usize,
+    },
+    /// A ball was launched, into the board or, during a bonus, into the attacker.
+    BallLaunched {
+        /// Number of balls left after the launch.
+        balls: usize,
+    },
+    /// A ball entered the start hole; the entry was drawn, held or wasted.
+    StartHoleEntered {
+        /// The hold after the entry was held or wasted (unchanged if it is drawn at once).
+        hold: Hold,
+    },
+    /// A jackpot was won outside rush mode and its category decided.
+    ///
+    /// Reported right before the `LotteryDrawn` event of the winning draw.
+    JackpotDecided(JackpotCategory),
+    /// A lottery was drawn.
+    LotteryDrawn {
+        /// Kind of the lottery.
+        mode: LotteryMode,
+        /// Outcome of the lottery.
+        result: LotteryResult,
+    },
+    /// Rush mode (or an ST rush) was entered.
+    RushEntered,
+    /// The rush continued into round `n`.
+    RushContinued {
+        /// Number of the new rush round.
+        n: usize,
+    },
+    /// The rush ended after `n` rounds.
+    RushEnded {
+        /// Number of rush rounds achieved.
+        n: usize,
+        /// Net balls gained from entering the rush until its end.
+        gained: usize,
+    },
+    /// Kakuhen mode was entered.
+    KakuhenEntered,
+    /// Kakuhen mode ended after `n` jackpots, by a jackpot leading elsewhere or a fall.
+    KakuhenEnded {
+        /// Number of jackpots won in a row in kakuhen mode, including the one entering it.
+        n: usize,
+    },
+    /// Jitan mode was entered, after a rush, a jackpot missing the rush or the ceiling.
+    JitanEntered {
+        /// Number of jitan spins granted.
+        spins: usize,
+    },
+    /// Jitan mode ended, by running out of spins or by a jackpot leading into rush or kakuhen.
+    JitanEnded,
+    /// Balls were paid out for a jackpot, at once or for a finished bonus round.
+    BallsAwarded {
+        /// Number of net balls paid out.
+        balls: usize,
+    },
+    /// A bonus round finished; see [`UserOutput::bonus_round`].
+    BonusRound(Bonus),
+    /// The ceiling fired; see [`UserOutput::ceiling`].
+    CeilingReached(Ceiling),
+    /// The game recovered from a problem; see [`UserOutput::probability_error`].
+    Warning(ProbabilityError),
+    /// A command was rejected; see [`UserOutput::command_rejected`].
+    CommandRejected(GameError),
+}
+
+/// Front-end that only consumes the [`GameEvent`] stream.
+///
+/// Every `EventHandler` is a [`UserOutput`] through a blanket implementation, so it can
+/// be passed to [`Game`](crate::Game) directly; the per-kind callbacks of `UserOutput`
+/// are ignored in favour of the events.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     CONFIG_EXAMPLE, Game,
+///     command::Command,
+///     event::{EventHandler, GameEvent},
+///     interface::{UserInput, UserOutput},
+/// };
+///
+/// struct Log(Vec<GameEvent>);
+///
+/// impl EventHandler for Log {
+///     fn handle_event(&mut self, event: GameEvent) {
+///         self.0.push(event);
+///     }
+/// }
+///
+/// struct NoInput;
+///
+/// impl<O: UserOutput> UserInput<O> for NoInput {
+///     fn wait_for_input(&mut self) -> Command<Self, O> {
+///         Command::FinishGame
+///     }
+/// }
+///
+/// let mut game = Game::new(CONFIG_EXAMPLE, NoInput, Log(Vec::new())).unwrap();
+/// game.start().unwrap();
+/// game.launch_ball().unwrap();
+///
+/// assert_eq!(
+///     game.output().0,
+///     [
+///         GameEvent::GameStarted { balls: 1000 },
+///         GameEvent::BallLaunched { balls: 999 },
+///     ]
+/// );
+/// ```
+pub trait EventHandler {
+    /// Handles a single event.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The `GameEvent` that just happened
+    fn handle_event(&mut self, event: GameEvent);
+}
+
+impl<H: EventHandler> UserOutput for H {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush_continue(&mut self, _result: LotteryResult) {}
+
+    fn lottery_kakuhen(&mut self, _result: LotteryResult) {}
+
+    fn event(&mut self, event: GameEvent) {
+        self.handle_event(event);
+    }
+}
@@ -1,24 +1,368 @@
 use std::{error::Error, fmt::Display, ops::ControlFlow};
 
-use rand::{Rng, rngs::ThreadRng};
+use rand::{SeedableRng, rngs::ThreadRng};
 
 use crate::{
-    command::Command,
-    config::{BallsConfig, Config, ConfigError},
+    command::{Command, RecordedCommand},
+    config::{
+        BallsConfig, Ceiling, CeilingReward, Config, ConfigError, Payout, RushContinueFn, RushModel,
+    },
+    event::{GameEvent, LotteryMode},
     interface::{UserInput, UserOutput},
-    lottery::Lottery,
+    lottery::{JackpotCategory, Lottery, LotteryResult, LotterySource},
 };
 
 /// Represents a state transition in the game.
 ///
 /// This structure captures both the previous state (if any) and the new state
 /// after a game action has been executed. Used primarily for output and logging purposes.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
 pub struct Transition {
     /// The game state before the transition occurred.
     pub before: Option<GameState>,
     /// The game state after the transition occurred.
     pub after: GameState,
+    /// The hold (pending start hole entries) after the transition occurred.
+    pub hold: Hold,
+    /// The jackpot being paid out after the transition occurred, if any.
+    pub bonus: Option<Bonus>,
+}
+
+/// Pending start hole entries (hold / 保留) and the spin clock of the reels.
+///
+/// When a ball enters the start hole while the reels are still spinning, the
+/// entry is stored until the current spin stops, up to `BallsConfig::hold_capacity`
+/// entries. Front-ends can use `pending` to draw the hold lamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Hold {
+    /// Number of entries waiting for a spin.
+    pub pending: usize,
+    /// Number of ball launches until the current spin stops (0 when the reels are idle).
+    pub spin_remaining: usize,
+    /// Number of entries lost because the hold was full.
+    pub wasted: usize,
+}
+
+/// Jackpot rounds being paid out (bonus phase / 大当たり).
+///
+/// A bonus starts when a jackpot hits and `Config::payout` is set. The game state
+/// already shows the mode entered by the jackpot, but until every round is played
+/// launched balls feed the attacker instead of the start hole, and no spin is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct Bonus {
+    /// Number of rounds of the jackpot.
+    pub rounds: usize,
+    /// Current round (starting from 1).
+    pub round: usize,
+    /// Number of balls that entered the attacker in the current round.
+    pub count: usize,
+}
+
+/// Saved game in progress, created by [`Game::snapshot`] and resumed by [`Game::restore`].
+///
+/// The snapshot holds the configuration, every piece of game state and the lottery RNG,
+/// so a restored game continues exactly like the original would have. It can be
+/// serialized with the `serde` feature if `F` and `R` can; `rand::rngs::StdRng` cannot,
+/// but e.g. `rand_chacha::ChaCha12Rng` with its `serde` feature can.
+/// A `ThreadRng` clone shares its generator with the game, so only a game with an owned
+/// RNG (such as `StdRng`) can be restored exactly.
+///
+/// The fields are public so that tests can start from any state, e.g. a rush in round 7.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct GameSnapshot<F: RushContinueFn = fn(usize) -> f64, R = ThreadRng> {
+    /// Configuration of the game.
+    pub config: Config<F>,
+    /// Current game state.
+    pub state: GameState,
+    /// Game state before the last command, reported as `Transition::before`.
+    pub before_state: Option<GameState>,
+    /// Pending start hole entries and spin clock.
+    pub hold: Hold,
+    /// Jackpot being paid out, if any.
+    pub bonus: Option<Bonus>,
+    /// Balls when the current rush was entered, reported by `GameEvent::RushEnded`.
+    pub rush_start_balls: usize,
+    /// Lottery random number generator.
+    pub rng: R,
+}
+
+/// Builder creating a [`Game`] that is already running in a chosen state.
+///
+/// Unlike [`Game::start`], which always enters `Normal` mode with `init_balls`, the
+/// builder can place the game in any state, e.g. a rush in round 7 with a single rush
+/// ball left. The state and hold are checked against the configuration when the game
+/// is built.
+///
+/// [`Game::builder`] and [`GameBuilder::new`] draw from `ThreadRng` and need an input
+/// for it; [`GameBuilder::with_rng`] takes any `LotterySource` up front, so it also
+/// builds games whose input is only implemented for e.g. `StdRng`.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     CONFIG_EXAMPLE, Game,
+///     command::Command,
+///     event::{EventHandler, GameEvent},
+///     game::{GameBuilder, GameState},
+///     interface::{UserInput, UserOutput},
+/// };
+/// use rand::{SeedableRng, rngs::StdRng};
+///
+/// struct NoInput;
+///
+/// impl<O: UserOutput> UserInput<O, fn(usize) -> f64, StdRng> for NoInput {
+///     fn wait_for_input(&mut self) -> Command<Self, O, fn(usize) -> f64, StdRng> {
+///         Command::FinishGame
+///     }
+/// }
+///
+/// struct NoOutput;
+///
+/// impl EventHandler for NoOutput {
+///     fn handle_event(&mut self, _event: GameEvent) {}
+/// }
+///
+/// let rush = GameState::Rush {
+///     balls: 500,
+///     rush_balls: 1,
+///     n: 7,
+/// };
+/// let rng = StdRng::seed_from_u64(42);
+/// let mut game = GameBuilder::with_rng(CONFIG_EXAMPLE, NoInput, NoOutput, rng)
+///     .state(rush)
+///     .build()
+///     .unwrap();
+///
+/// // The last rush ball ends the rush
+/// game.launch_ball().unwrap();
+/// assert_eq!(*game.state(), GameState::Normal { balls: 500, spins: 0 });
+/// ```
+pub struct GameBuilder<I, O, F: RushContinueFn = fn(usize) -> f64, R = ThreadRng> {
+    config: Config<F>,
+    input: I,
+    output: O,
+    state: GameState,
+    hold: Hold,
+    rng: R,
+}
+
+impl<I, O, F: RushContinueFn> GameBuilder<I, O, F> {
+    /// Creates a builder for a game in `Normal` mode with `init_balls`, drawing from `ThreadRng`.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Game configuration including probabilities and ball settings
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    pub fn new(config: Config<F>, input: I, output: O) -> Self {
+        Self {
+            state: GameState::Normal {
+                balls: config.balls.init_balls,
+                spins: 0,
+            },
+            config,
+            input,
+            output,
+            hold: Hold::default(),
+            rng: ThreadRng::default(),
+        }
+    }
+}
+
+impl<I, O, F: RushContinueFn, R: LotterySource> GameBuilder<I, O, F, R> {
+    /// Creates a builder for a game in `Normal` mode with `init_balls`, drawing from `rng`.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Game configuration including probabilities and ball settings
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    /// - `rng`: Random number generator or other `LotterySource` used for every lottery draw
+    pub fn with_rng(config: Config<F>, input: I, output: O, rng: R) -> Self {
+        Self {
+            state: GameState::Normal {
+                balls: config.balls.init_balls,
+                spins: 0,
+            },
+            config,
+            input,
+            output,
+            hold: Hold::default(),
+            rng,
+        }
+    }
+}
+
+impl<I, O, F: RushContinueFn, R> GameBuilder<I, O, F, R> {
+    /// Sets the state the game starts in.
+    ///
+    /// `GameState::Uninitialized` builds a game that still has to be started.
+    pub fn state(mut self, state: GameState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the pending start hole entries and the spin clock.
+    pub fn hold(mut self, hold: Hold) -> Self {
+        self.hold = hold;
+        self
+    }
+
+    /// Sets the random number generator used for every lottery draw.
+    ///
+    /// Any other `LotterySource`, such as a [`ScriptedLottery`](crate::lottery::ScriptedLottery),
+    /// works as well.
+    pub fn rng<R2: LotterySource>(self, rng: R2) -> GameBuilder<I, O, F, R2> {
+        GameBuilder {
+            config: self.config,
+            input: self.input,
+            output: self.output,
+            state: self.state,
+            hold: self.hold,
+            rng,
+        }
+    }
+}
+
+impl<I, O, F, R> GameBuilder<I, O, F, R>
+where
+    I: UserInput<O, F, R>,
+    O: UserOutput,
+    F: RushContinueFn,
+    R: LotterySource,
+{
+    /// Builds the game.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Game)` running in the chosen state
+    /// - `Err(ConfigError)` if the configuration contains invalid values, or the state
+    ///   or hold cannot occur with it
+    pub fn build(self) -> Result<Game<I, O, F, R>, ConfigError> {
+        let mut game = Game::with_rng(self.config, self.input, self.output, self.rng)?;
+        validate_state(&self.state, &self.hold, &game.config)?;
+        game.state = self.state;
+        game.hold = self.hold;
+        game.rush_start_balls = self.state.balls();
+        Ok(game)
+    }
+}
+
+/// Checks that `state` and `hold` can occur in a game with `config`.
+fn validate_state(state: &GameState, hold: &Hold, config: &BallsConfig) -> Result<(), ConfigError> {
+    let mut error = ConfigError::new();
+    if !state.is_uninitialized() && state.balls() < 1 {
+        error.push("state.balls: must be greater than 0".to_string());
+    }
+    match *state {
+        GameState::Uninitialized => {}
+        GameState::Normal { spins, .. } => {
+            if let Some(ceiling) = config.ceiling.filter(|ceiling| spins >= ceiling.spins) {
+                error.push(format!(
+                    "state.spins: must be less than ceiling.spins ({})",
+                    ceiling.spins
+                ));
+            }
+        }
+        GameState::Rush { rush_balls, n, .. } => {
+            if config.rush_model != RushModel::ContinueLottery {
+                error.push("state: Rush requires rush_model ContinueLottery".to_string());
+            }
+            if rush_balls < 1 {
+                error.push("state.rush_balls: must be greater than 0".to_string());
+            }
+            if n < 1 {
+                error.push("state.n: must be greater than 0".to_string());
+            }
+        }
+        GameState::SpecialTimes {
+            remaining_spins, n, ..
+        } => match config.rush_model {
+            RushModel::SpecialTimes { spins } => {
+                if !(1..=spins).contains(&remaining_spins) {
+                    error.push(format!(
+                        "state.remaining_spins: must be between 1 and rush_model.spins ({spins})"
+                    ));
+                }
+                if n < 1 {
+                    error.push("state.n: must be greater than 0".to_string());
+                }
+            }
+            RushModel::ContinueLottery => {
+                error.push("state: SpecialTimes requires rush_model SpecialTimes".to_string());
+            }
+        },
+        GameState::Kakuhen { n, .. } => {
+            if n < 1 {
+                error.push("state.n: must be greater than 0".to_string());
+            }
+        }
+        GameState::Jitan {
+            remaining_spins, ..
+        } => {
+            if remaining_spins < 1 {
+                error.push("state.remaining_spins: must be greater than 0".to_string());
+            }
+        }
+    }
+
+    if hold.pending > config.hold_capacity {
+        error.push(format!(
+            "hold.pending: must not exceed hold_capacity ({})",
+            config.hold_capacity
+        ));
+    }
+    let spin_duration = match state {
+        GameState::Jitan { .. } => config.jitan_spin_duration,
+        _ => config.spin_duration,
+    };
+    if hold.spin_remaining > spin_duration {
+        error.push(format!(
+            "hold.spin_remaining: must not exceed the spin duration ({spin_duration})"
+        ));
+    }
+    if error.is_empty() { Ok(()) } else { Err(error) }
+}
+
+/// Checks that `bonus` can be paid out in `state` with `payout`.
+fn validate_bonus(
+    bonus: Option<&Bonus>,
+    state: &GameState,
+    payout: Option<&Payout>,
+) -> Result<(), ConfigError> {
+    let Some(bonus) = bonus else {
+        return Ok(());
+    };
+    let mut error = ConfigError::new();
+    let Some(payout) = payout else {
+        error.push("bonus: requires config.payout".to_string());
+        return Err(error);
+    };
+    if state.is_uninitialized() {
+        error.push("bonus: requires a started game".to_string());
+    }
+    if !(1..=bonus.rounds).contains(&bonus.round) {
+        error.push(format!(
+            "bonus.round: must be between 1 and bonus.rounds ({})",
+            bonus.rounds
+        ));
+    }
+    if bonus.count >= payout.counts {
+        error.push(format!(
+            "bonus.count: must be less than payout.counts ({})",
+            payout.counts
+        ));
+    }
+    if error.is_empty() { Ok(()) } else { Err(error) }
 }
 
 /// Error indicating that an operation was attempted on an uninitialized game.
@@ -50,13 +394,53 @@ impl Display for AlreadyStartedError {
 
 impl Error for AlreadyStartedError {}
 
+/// Error returned when a command is rejected in the current game state.
+///
+/// Returned by [`ControlCommand::execute`](crate::command::ControlCommand::execute) and
+/// [`Game::run_step_with_command`], and reported to [`UserOutput::command_rejected`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameError {
+    /// The game is not running.
+    Uninitialized(UninitializedError),
+    /// The game is already running.
+    AlreadyStarted(AlreadyStartedError),
+}
+
+impl Display for GameError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Uninitialized(error) => write!(f, "{error}"),
+            Self::AlreadyStarted(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl Error for GameError {}
+
+impl From<UninitializedError> for GameError {
+    fn from(error: UninitializedError) -> Self {
+        Self::Uninitialized(error)
+    }
+}
+
+impl From<AlreadyStartedError> for GameError {
+    fn from(error: AlreadyStartedError) -> Self {
+        Self::AlreadyStarted(error)
+    }
+}
+
 /// Represents the current state of the pachislot game.
 ///
-/// The game can be in one of three states:
+/// The game can be in one of six states:
 /// - `Uninitialized`: Game has not been started yet
 /// - `Normal`: Standard gameplay mode with a certain number of balls
 /// - `Rush`: Special bonus mode with additional balls and continuation mechanics
-#[derive(Clone, Copy, Debug)]
+/// - `SpecialTimes`: Rush lasting a fixed number of spins (`RushModel::SpecialTimes`)
+/// - `Kakuhen`: Probability variable mode with boosted jackpot odds until the next jackpot
+/// - `Jitan`: Time-shortening mode for a fixed number of spins after a rush
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum GameState {
     /// Game has not been initialized or has ended.
     Uninitialized,
@@ -64,6 +448,10 @@ pub enum GameState {
     Normal {
         /// Number of balls available for play.
         balls: usize,
+        /// Number of consecutive losing spins since entering normal mode.
+        ///
+        /// Drives the ceiling (`BallsConfig::ceiling`).
+        spins: usize,
     },
     /// Rush (bonus) mode with enhanced winning chances.
     Rush {
@@ -74,6 +462,39 @@ pub enum GameState {
         /// Number of consecutive rush rounds achieved.
         n: usize,
     },
+    /// ST (special times) rush, used instead of `Rush` with `RushModel::SpecialTimes`.
+    ///
+    /// Spins draw the rush lottery. Launches do not consume balls, like rush balls
+    /// in the continue-lottery model; the rush ends when the spins run out.
+    SpecialTimes {
+        /// Total number of balls available.
+        balls: usize,
+        /// Number of spins left before the rush ends.
+        remaining_spins: usize,
+        /// Number of consecutive rush rounds achieved.
+        n: usize,
+    },
+    /// Kakuhen (probability variable) mode with boosted jackpot odds.
+    ///
+    /// Balls are consumed like in normal mode. The mode lasts until the next
+    /// jackpot, which is routed into kakuhen or rush mode again, or until a
+    /// losing spin falls back to normal mode.
+    Kakuhen {
+        /// Number of balls available for play.
+        balls: usize,
+        /// Number of consecutive jackpots routed into kakuhen mode.
+        n: usize,
+    },
+    /// Jitan (time-shortening) mode granted after a rush ends.
+    ///
+    /// Jackpot odds are those of normal mode, but spins are shorter and the electric
+    /// tulip helps balls into the start hole. Balls are consumed like in normal mode.
+    Jitan {
+        /// Number of balls available for play.
+        balls: usize,
+        /// Number of spins left before returning to normal mode.
+        remaining_spins: usize,
+    },
 }
 
 impl GameState {
@@ -87,16 +508,22 @@ impl GameState {
     /// # State Changes
     ///
     /// - In Normal mode: Decrements balls count, transitions to Uninitialized if no balls remain
-    /// - In Rush mode: Decrements rush_balls count, transitions to Normal mode when rush_balls reach 0
-    pub(crate) fn launch_ball(&mut self) -> Result<(), UninitializedError> {
+    /// - In Rush mode: Decrements rush_balls count, transitions to Jitan mode (or Normal mode
+    ///   when `jitan_spins` is 0) when rush_balls reach 0
+    /// - In SpecialTimes mode: Keeps the balls count; the rush ends by spins instead
+    /// - In Kakuhen and Jitan mode: Decrements balls count, transitions to Uninitialized if no balls remain
+    pub(crate) fn launch_ball(&mut self, config: &BallsConfig) -> Result<(), UninitializedError> {
         match self {
             Self::Uninitialized => Err(UninitializedError),
-            Self::Normal { balls } => {
+            Self::Normal { balls, spins } => {
                 let balls = *balls - 1;
                 if balls == 0 {
                     *self = Self::Uninitialized;
                 } else {
-                    *self = Self::Normal { balls };
+                    *self = Self::Normal {
+                        balls,
+                        spins: *spins,
+                    };
                 }
                 Ok(())
             }
@@ -107,7 +534,7 @@ impl GameState {
             } => {
                 let rush_balls = *rush_balls - 1;
                 if rush_balls == 0 {
-                    *self = Self::Normal { balls: *balls };
+                    *self = Self::after_rush(*balls, config);
                 } else {
                     *self = Self::Rush {
                         balls: *balls,
@@ -117,6 +544,95 @@ impl GameState {
                 }
                 Ok(())
             }
+            Self::SpecialTimes { .. } => Ok(()),
+            Self::Kakuhen { balls, n } => {
+                let balls = *balls - 1;
+                if balls == 0 {
+                    *self = Self::Uninitialized;
+                } else {
+                    *self = Self::Kakuhen { balls, n: *n };
+                }
+                Ok(())
+            }
+            Self::Jitan {
+                balls,
+                remaining_spins,
+            } => {
+                let balls = *balls - 1;
+                if balls == 0 {
+                    *self = Self::Uninitialized;
+                } else {
+                    *self = Self::Jitan {
+                        balls,
+                        remaining_spins: *remaining_spins,
+                    };
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The state entered when a rush ends with `balls` balls.
+    fn after_rush(balls: usize, config: &BallsConfig) -> Self {
+        if config.jitan_spins > 0 {
+            Self::Jitan {
+                balls,
+                remaining_spins: config.jitan_spins,
+            }
+        } else {
+            Self::Normal { balls, spins: 0 }
+        }
+    }
+
+    /// Consumes one jitan spin, returning to normal mode when none are left.
+    pub(crate) fn consume_jitan_spin(&mut self) {
+        if let Self::Jitan {
+            balls,
+            remaining_spins,
+        } = self
+        {
+            *remaining_spins -= 1;
+            if *remaining_spins == 0 {
+                *self = Self::Normal {
+                    balls: *balls,
+                    spins: 0,
+                };
+            }
+        }
+    }
+
+    /// Consumes one ST spin, ending the rush when none are left.
+    pub(crate) fn consume_special_times_spin(&mut self, config: &BallsConfig) {
+        if let Self::SpecialTimes {
+            balls,
+            remaining_spins,
+            ..
+        } = self
+        {
+            *remaining_spins -= 1;
+            if *remaining_spins == 0 {
+                *self = Self::after_rush(*balls, config);
+            }
+        }
+    }
+
+    /// Returns the player's balls, or 0 when the game is not running.
+    pub fn balls(&self) -> usize {
+        match self {
+            Self::Uninitialized => 0,
+            Self::Normal { balls, .. }
+            | Self::Rush { balls, .. }
+            | Self::SpecialTimes { balls, .. }
+            | Self::Kakuhen { balls, .. }
+            | Self::Jitan { balls, .. } => *balls,
+        }
+    }
+
+    /// Returns the number of the current rush round, or `None` outside rush.
+    pub(crate) fn rush_round(&self) -> Option<usize> {
+        match self {
+            Self::Rush { n, .. } | Self::SpecialTimes { n, .. } => Some(*n),
+            _ => None,
         }
     }
 
@@ -128,6 +644,7 @@ impl GameState {
         if self.is_uninitialized() {
             *self = Self::Normal {
                 balls: config.init_balls,
+                spins: 0,
             };
             Ok(())
         } else {
@@ -136,44 +653,122 @@ impl GameState {
     }
 
     pub(crate) fn increment_balls(&mut self, config: &BallsConfig) {
+        self.add_balls(config.incremental_balls);
+    }
+
+    /// Feeds one ball into the attacker, which pays out `payout` balls for it.
+    pub(crate) fn feed_attacker(&mut self, payout: usize) {
+        self.add_balls(payout - 1);
+    }
+
+    fn add_balls(&mut self, n: usize) {
         match self {
             Self::Uninitialized => unreachable!(),
-            Self::Normal { balls } => *balls += config.incremental_balls,
-            Self::Rush { balls, .. } => {
-                *balls += config.incremental_balls;
+            Self::Normal { balls, .. }
+            | Self::Rush { balls, .. }
+            | Self::SpecialTimes { balls, .. }
+            | Self::Kakuhen { balls, .. }
+            | Self::Jitan { balls, .. } => {
+                *balls += n;
             }
         }
     }
 
-    pub(crate) fn is_rush(&self) -> bool {
-        match self {
-            Self::Uninitialized => false,
-            Self::Normal { .. } => false,
-            Self::Rush { .. } => true,
+    /// Falls back from kakuhen mode to normal mode, keeping the balls.
+    pub(crate) fn fall(&mut self) {
+        if let Self::Kakuhen { balls, .. } = self {
+            *self = Self::Normal {
+                balls: *balls,
+                spins: 0,
+            };
+        }
+    }
+
+    /// Counts a losing spin in normal mode.
+    ///
+    /// # Returns
+    ///
+    /// The number of consecutive losing spins, or `None` outside normal mode.
+    pub(crate) fn count_losing_spin(&mut self) -> Option<usize> {
+        if let Self::Normal { spins, .. } = self {
+            *spins += 1;
+            Some(*spins)
+        } else {
+            None
+        }
+    }
+
+    /// Forces the mode granted by the ceiling, keeping the balls.
+    pub(crate) fn trigger_ceiling(&mut self, ceiling: &Ceiling, config: &BallsConfig) {
+        if let Self::Normal { balls, .. } = self {
+            match ceiling.reward {
+                CeilingReward::Jitan { spins } => {
+                    *self = Self::Jitan {
+                        balls: *balls,
+                        remaining_spins: spins,
+                    }
+                }
+                CeilingReward::Rush => self.trigger_rush(config),
+            }
         }
     }
 
     /// Into RUSH or Continue RUSH
-    /// Include Incremental Balls and Rush Balls
+    /// Include Rush Balls (or ST spins); the jackpot itself is paid out separately
     pub(crate) fn trigger_rush(&mut self, config: &BallsConfig) {
         match self {
             Self::Uninitialized => unreachable!(),
-            Self::Normal { balls } => {
-                *self = Self::Rush {
-                    balls: *balls + config.incremental_balls,
-                    rush_balls: config.incremental_rush,
-                    n: 1,
+            Self::Normal { balls, .. }
+            | Self::Kakuhen { balls, .. }
+            | Self::Jitan { balls, .. } => {
+                let balls = *balls;
+                *self = match config.rush_model {
+                    RushModel::ContinueLottery => Self::Rush {
+                        balls,
+                        rush_balls: config.incremental_rush,
+                        n: 1,
+                    },
+                    RushModel::SpecialTimes { spins } => Self::SpecialTimes {
+                        balls,
+                        remaining_spins: spins,
+                        n: 1,
+                    },
                 }
             }
-            Self::Rush {
-                balls,
-                rush_balls,
-                n,
-            } => {
-                *balls += config.incremental_balls;
+            Self::Rush { rush_balls, n, .. } => {
                 *rush_balls += config.incremental_rush;
                 *n += 1;
             }
+            Self::SpecialTimes {
+                remaining_spins, n, ..
+            } => {
+                if let RushModel::SpecialTimes { spins } = config.rush_model {
+                    *remaining_spins = spins;
+                }
+                *n += 1;
+            }
+        }
+    }
+
+    /// A jackpot missing the rush continues like a finished rush, keeping the balls
+    pub(crate) fn miss_rush(&mut self, config: &BallsConfig) {
+        *self = Self::after_rush(self.balls(), config);
+    }
+
+    /// Into KAKUHEN or Continue KAKUHEN
+    /// The jackpot itself is paid out separately
+    pub(crate) fn trigger_kakuhen(&mut self) {
+        match self {
+            Self::Uninitialized | Self::Rush { .. } | Self::SpecialTimes { .. } => unreachable!(),
+            Self::Normal { balls, .. } | Self::Jitan { balls, .. } => {
+                *self = Self::Kakuhen {
+                    balls: *balls,
+                    n: 1,
+                }
+            }
+            Self::Kakuhen { n, .. } => {
+                *n += 1;
+            }
         }
     }
 }
@@ -189,22 +784,30 @@ impl GameState {
 ///
 /// - `I`: User input handler implementing `UserInput<O>`
 /// - `O`: User output handler implementing `UserOutput`
-/// - `F`: Probability function type implementing `FnMut(usize) -> f64`
-/// - `R`: Random number generator implementing `Rng`
-pub struct Game<I, O, F: FnMut(usize) -> f64 = fn(usize) -> f64, R = ThreadRng>
+/// - `F`: Probability function type implementing `RushContinueFn`
+/// - `R`: Source of lottery outcomes implementing `LotterySource`, e.g. any `Rng`
+pub struct Game<I, O, F: RushContinueFn = fn(usize) -> f64, R = ThreadRng>
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    R: Rng,
+    R: LotterySource,
 {
     /// Previous game state for transition tracking.
     before_state: Option<GameState>,
     /// Current game state.
     state: GameState,
+    /// Pending start hole entries and spin clock.
+    hold: Hold,
+    /// Jackpot being paid out, if any.
+    bonus: Option<Bonus>,
     /// Lottery system for determining outcomes.
     lottery: Lottery<F, R>,
     /// Ball-related configuration settings.
     config: BallsConfig,
+    /// Jackpot round/payout structure.
+    payout: Option<Payout>,
+    /// Balls when the current rush was entered, for `GameEvent::RushEnded`.
+    rush_start_balls: usize,
     /// User input handler.
     input: I,
     /// User output handler.
@@ -215,8 +818,8 @@ impl<I, O, F, R> Game<I, O, F, R>
 where
     I: UserInput<O, F, R>,
     O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng + Default,
+    F: RushContinueFn,
+    R: LotterySource + Default,
 {
     /// Creates a new Game instance with the specified configuration and I/O handlers.
     ///
@@ -239,25 +842,180 @@ where
     /// let game = Game::new(CONFIG_EXAMPLE, input, output)?;
     /// ```
     pub fn new(config: Config<F>, input: I, output: O) -> Result<Self, ConfigError> {
+        Self::with_rng(config, input, output, R::default())
+    }
+}
+
+impl<I, O, F> Game<I, O, F>
+where
+    I: UserInput<O, F>,
+    O: UserOutput,
+    F: RushContinueFn,
+{
+    /// Creates a builder for a game that starts in a chosen state; see [`GameBuilder`].
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Game configuration including probabilities and ball settings
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    ///
+    /// # Returns
+    ///
+    /// A `GameBuilder` for a game in `Normal` mode with `init_balls`, drawing from `ThreadRng`.
+    /// Use [`GameBuilder::with_rng`] if `input` is not implemented for `ThreadRng`.
+    pub fn builder(config: Config<F>, input: I, output: O) -> GameBuilder<I, O, F> {
+        GameBuilder::new(config, input, output)
+    }
+}
+
+impl<I, O, F, R> Game<I, O, F, R>
+where
+    I: UserInput<O, F, R>,
+    O: UserOutput,
+    F: RushContinueFn,
+    R: LotterySource + SeedableRng,
+{
+    /// Creates a new Game instance whose lottery is driven by an RNG seeded with `seed`.
+    ///
+    /// Two games created from the same configuration and seed, and fed the same
+    /// commands, produce exactly the same sequence of lottery results and state
+    /// transitions.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Game configuration including probabilities and ball settings
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    /// - `seed`: Seed for the lottery random number generator
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Game)` if the configuration is valid
+    /// - `Err(ConfigError)` if the configuration contains invalid values
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// use pachislo::{Game, CONFIG_EXAMPLE};
+    /// use rand::rngs::StdRng;
+    ///
+    /// let game: Game<_, _, _, StdRng> = Game::from_seed(CONFIG_EXAMPLE, input, output, 42)?;
+    /// ```
+    pub fn from_seed(
+        config: Config<F>,
+        input: I,
+        output: O,
+        seed: u64,
+    ) -> Result<Self, ConfigError> {
+        Self::with_rng(config, input, output, R::seed_from_u64(seed))
+    }
+}
+
+impl<I, O, F, R> Game<I, O, F, R>
+where
+    I: UserInput<O, F, R>,
+    O: UserOutput,
+    F: RushContinueFn,
+    R: LotterySource,
+{
+    /// Creates a new Game instance whose lottery draws from the given random number generator.
+    ///
+    /// This is the most general constructor; use it with a seeded RNG to replay a
+    /// session bit-for-bit.
+    ///
+    /// # Arguments
+    ///
+    /// - `config`: Game configuration including probabilities and ball settings
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    /// - `rng`: Random number generator used for every lottery draw
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Game)` if the configuration is valid
+    /// - `Err(ConfigError)` if the configuration contains invalid values
+    pub fn with_rng(
+        mut config: Config<F>,
+        input: I,
+        output: O,
+        rng: R,
+    ) -> Result<Self, ConfigError> {
         config.validate()?;
         Ok(Self {
             before_state: None,
             state: GameState::Uninitialized,
-            lottery: Lottery::new(config.probability),
+            hold: Hold::default(),
+            bonus: None,
+            lottery: Lottery::with_rng(config.probability, rng),
             config: config.balls,
+            payout: config.payout,
+            rush_start_balls: 0,
             input,
             output,
         })
     }
-}
 
-impl<I, O, F, R> Game<I, O, F, R>
-where
-    I: UserInput<O, F, R>,
-    O: UserOutput,
-    F: FnMut(usize) -> f64,
-    R: Rng,
-{
+    /// Resumes a game saved by [`Game::snapshot`].
+    ///
+    /// # Arguments
+    ///
+    /// - `snapshot`: The saved game
+    /// - `input`: User input handler
+    /// - `output`: User output handler
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Game)` continuing from the snapshot
+    /// - `Err(ConfigError)` if the configuration of the snapshot contains invalid values,
+    ///   or its state, hold or bonus cannot occur with it
+    pub fn restore(snapshot: GameSnapshot<F, R>, input: I, output: O) -> Result<Self, ConfigError> {
+        let GameSnapshot {
+            config,
+            state,
+            before_state,
+            hold,
+            bonus,
+            rush_start_balls,
+            rng,
+        } = snapshot;
+
+        let mut game = Self::with_rng(config, input, output, rng)?;
+        validate_state(&state, &hold, &game.config)?;
+        validate_bonus(bonus.as_ref(), &state, game.payout.as_ref())?;
+        game.state = state;
+        game.before_state = before_state;
+        game.hold = hold;
+        game.bonus = bonus;
+        game.rush_start_balls = rush_start_balls;
+        Ok(game)
+    }
+
+    /// Saves the game in progress.
+    ///
+    /// # Returns
+    ///
+    /// A `GameSnapshot` from which [`Game::restore`] continues exactly like this game.
+    pub fn snapshot(&self) -> GameSnapshot<F, R>
+    where
+        F: Clone,
+        R: Clone,
+    {
+        GameSnapshot {
+            config: Config {
+                balls: self.config,
+                probability: self.lottery.probability().clone(),
+                payout: self.payout.clone(),
+            },
+            state: self.state,
+            before_state: self.before_state,
+            hold: self.hold,
+            bonus: self.bonus,
+            rush_start_balls: self.rush_start_balls,
+            rng: self.lottery.rng().clone(),
+        }
+    }
+
     /// Executes a single step of the game loop.
     ///
     /// This method waits for user input, processes the returned command, and updates
@@ -266,9 +1024,10 @@ where
     ///
     /// # Returns
     ///
-    /// - `ControlFlow::Continue(())` if the game should continue running
-    /// - `ControlFlow::Break(())` if the game should terminate
-    pub fn run_step(&mut self) -> ControlFlow<()> {
+    /// - `Ok(ControlFlow::Continue(()))` if the game should continue running
+    /// - `Ok(ControlFlow::Break(()))` if the game should terminate
+    /// - `Err(GameError)` if the command was rejected
+    pub fn run_step(&mut self) -> Result<ControlFlow<()>, GameError> {
         let command = self.input.wait_for_input();
 
         self.run_step_with_command(command)
@@ -286,33 +1045,50 @@ where
     ///
     /// # Returns
     ///
-    /// - `ControlFlow::Continue(())` if the game should continue running
-    /// - `ControlFlow::Break(())` if the game should terminate
-    pub fn run_step_with_command(&mut self, command: Command<I, O, F, R>) -> ControlFlow<()> {
+    /// - `Ok(ControlFlow::Continue(()))` if the game should continue running
+    /// - `Ok(ControlFlow::Break(()))` if the game should terminate
+    /// - `Err(GameError)` if the command was rejected; the game keeps running and the
+    ///   error is also reported to `UserOutput::command_rejected`
+    pub fn run_step_with_command(
+        &mut self,
+        command: Command<I, O, F, R>,
+    ) -> Result<ControlFlow<()>, GameError> {
+        self.output.command_received(match &command {
+            Command::Control(cmd) => cmd.recorded(),
+            Command::FinishGame => Some(RecordedCommand::Quit),
+        });
+
         self.output.default(Transition {
             before: self.before_state,
             after: self.state,
+            hold: self.hold,
+            bonus: self.bonus,
         });
 
         self.before_state = Some(self.state);
 
         let mut command = match command {
             Command::Control(cmd) => cmd,
-            Command::FinishGame => return ControlFlow::Break(()),
+            Command::FinishGame => return Ok(ControlFlow::Break(())),
         };
 
-        command.execute(self);
+        if let Err(error) = command.execute(self) {
+            self.output.command_rejected(error);
+            self.emit(GameEvent::CommandRejected(error));
+            return Err(error);
+        }
 
-        ControlFlow::Continue(())
+        Ok(ControlFlow::Continue(()))
     }
 
     /// Runs the main game loop until termination.
     ///
     /// This method continuously calls `run_step()` until the game decides to terminate.
-    /// Use this for a complete game session from start to finish.
+    /// Use this for a complete game session from start to finish. Rejected commands
+    /// do not end the loop.
     pub fn run(&mut self) {
         loop {
-            if self.run_step().is_break() {
+            if let Ok(ControlFlow::Break(())) = self.run_step() {
                 break;
             }
         }
@@ -325,7 +1101,13 @@ where
     /// - `Ok(())` if the game was successfully started
     /// - `Err(AlreadyStartedError)` if the game is already running
     pub fn start(&mut self) -> Result<(), AlreadyStartedError> {
-        self.state.init(&self.config)
+        self.state.init(&self.config)?;
+        self.hold = Hold::default();
+        self.bonus = None;
+        self.emit(GameEvent::GameStarted {
+            balls: self.state.balls(),
+        });
+        Ok(())
     }
 
     /// Finishes the current game session and resets to uninitialized state.
@@ -340,8 +1122,12 @@ where
         }
 
         self.output.finish_game(&self.state);
+        self.emit(GameEvent::GameFinished {
+            balls: self.state.balls(),
+        });
 
         self.state = GameState::Uninitialized;
+        self.bonus = None;
 
         Ok(())
     }
@@ -350,71 +1136,407 @@ where
     ///
     /// This decrements the available ball count and may trigger state transitions
     /// (e.g., from Rush mode back to Normal mode when rush balls are exhausted).
+    /// Each launch also advances the spin clock; when the current spin stops and an
+    /// entry is held, the next spin starts and its lottery is drawn.
+    ///
+    /// During a bonus the ball feeds the attacker instead and the spin clock stands still.
     ///
     /// # Returns
     ///
     /// - `Ok(())` if the ball was successfully launched
     /// - `Err(UninitializedError)` if the game is not running
     pub fn launch_ball(&mut self) -> Result<(), UninitializedError> {
-        self.state.launch_ball()
+        if self.state.is_uninitialized() {
+            return Err(UninitializedError);
+        }
+
+        if self.bonus.is_some() {
+            self.feed_attacker();
+            return Ok(());
+        }
+
+        let before = self.state;
+        self.state.launch_ball(&self.config)?;
+        self.emit(GameEvent::BallLaunched {
+            balls: self.state.balls(),
+        });
+        if self.state.is_uninitialized() {
+            self.emit(GameEvent::GameFinished { balls: 0 });
+        } else {
+            self.emit_mode_events(before);
+        }
+
+        if self.hold.spin_remaining > 0 {
+            self.hold.spin_remaining -= 1;
+            self.drain_hold();
+        }
+
+        Ok(())
     }
 
-    /// Triggers a lottery event based on the current game state.
+    /// Handles a ball entering the start hole.
     ///
-    /// The lottery behavior depends on whether the game is in Normal or Rush mode:
-    /// - In Normal mode: Uses normal lottery probabilities
-    /// - In Rush mode: Uses enhanced rush probabilities and handles continuation logic
+    /// If the reels are idle the entry is drawn immediately. Otherwise, or during a
+    /// bonus, it is held until the current spin stops, or counted as wasted when the
+    /// hold is full.
     ///
-    /// Winning a lottery may trigger rush mode or continue an existing rush sequence.
-    pub fn cause_lottery(&mut self) {
-        let result;
-        if self.state.is_rush() {
-            result = self.lottery.lottery_rush();
-            self.output.lottery_rush(result);
+    /// # Returns
+    ///
+    /// - `Ok(())` if the entry was drawn, held or wasted
+    /// - `Err(UninitializedError)` if the game is not running
+    pub fn enter_start_hole(&mut self) -> Result<(), UninitializedError> {
+        if self.state.is_uninitialized() {
+            return Err(UninitializedError);
+        }
+
+        let idle = self.hold.spin_remaining == 0 && self.hold.pending == 0 && self.bonus.is_none();
+        if idle {
+            self.emit(GameEvent::StartHoleEntered { hold: self.hold });
+            self.start_spin();
+            return Ok(());
+        }
+
+        if self.hold.pending < self.config.hold_capacity {
+            self.hold.pending += 1;
         } else {
-            result = self.lottery.lottery_normal();
-            self.output.lottery_normal(result);
+            self.hold.wasted += 1;
         }
+        self.emit(GameEvent::StartHoleEntered { hold: self.hold });
 
-        if !(result.is_win()) {
+        Ok(())
+    }
+
+    /// Feeds a launched ball into the attacker, finishing the round after `counts` balls.
+    fn feed_attacker(&mut self) {
+        let (Some(payout), Some(bonus)) = (&self.payout, &mut self.bonus) else {
+            return;
+        };
+
+        self.state.feed_attacker(payout.balls_per_count);
+        self.output.event(GameEvent::BallLaunched {
+            balls: self.state.balls(),
+        });
+        bonus.count += 1;
+        if bonus.count < payout.counts {
             return;
         }
 
-        // When win the lottery
+        let finished = *bonus;
+        if bonus.round < bonus.rounds {
+            bonus.round += 1;
+            bonus.count = 0;
+        } else {
+            self.bonus = None;
+        }
+        self.output.bonus_round(finished);
+        self.output.event(GameEvent::BonusRound(finished));
+        self.output.event(GameEvent::BallsAwarded {
+            balls: payout.counts * (payout.balls_per_count - 1),
+        });
+        self.drain_hold();
+    }
 
-        let GameState::Rush { n, .. } = self.state else {
-            self.state.trigger_rush(&self.config);
+    /// Starts the spins of held entries while the reels are idle.
+    fn drain_hold(&mut self) {
+        while self.hold.spin_remaining == 0
+            && self.hold.pending > 0
+            && self.bonus.is_none()
+            && !self.state.is_uninitialized()
+        {
+            self.hold.pending -= 1;
+            self.start_spin();
+        }
+    }
+
+    /// Starts a spin of the reels and draws its lottery.
+    fn start_spin(&mut self) {
+        self.hold.spin_remaining = match self.state {
+            GameState::Jitan { .. } => self.config.jitan_spin_duration,
+            _ => self.config.spin_duration,
+        };
+        self.cause_lottery();
+    }
+
+    /// Lets the electric tulip guide a ball that missed the start hole into it.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the game is in Jitan mode and the ball is guided into the start hole
+    /// (with probability `jitan_start_hole`), `false` otherwise.
+    pub fn assist_start_hole(&mut self) -> bool {
+        matches!(self.state, GameState::Jitan { .. }) && self.lottery.lottery_jitan_start_hole()
+    }
+
+    /// Triggers a lottery event based on the current game state.
+    ///
+    /// The lottery is drawn immediately, bypassing the hold and the spin clock;
+    /// use [`Game::enter_start_hole`] to simulate a ball entering the start hole.
+    ///
+    /// The lottery behavior depends on the current game mode:
+    /// - In Normal mode: Uses normal lottery probabilities; a loss counts towards the ceiling
+    /// - In Kakuhen mode: Uses boosted kakuhen probabilities; a loss may fall back to Normal mode
+    /// - In Jitan mode: Uses normal lottery probabilities and consumes one jitan spin
+    /// - In Rush mode: Uses enhanced rush probabilities and handles continuation logic
+    /// - In SpecialTimes mode: Uses enhanced rush probabilities; a win continues the rush with
+    ///   fresh spins, a loss consumes one spin
+    ///
+    /// A jackpot in Normal, Kakuhen or Jitan mode is routed into Kakuhen mode with probability
+    /// `kakuhen_entry`, otherwise into Rush mode with probability `rush_entry`; a jackpot
+    /// missing the rush continues in Jitan (or Normal) mode. Winning in Rush mode may continue
+    /// the existing rush sequence. Each jackpot is paid out at once, or as a bonus when
+    /// `Config::payout` is set. Nothing happens if the game is not running or a bonus is
+    /// being paid out.
+    pub fn cause_lottery(&mut self) {
+        if self.bonus.is_some() {
+            return;
+        }
+
+        match self.state {
+            GameState::Uninitialized => {}
+            GameState::Normal { .. } => {
+                let result = self.lottery.lottery_normal();
+                let category = self.decide_jackpot(result);
+                self.output.lottery_normal(result);
+                self.emit_lottery(LotteryMode::Normal, result);
+
+                match category {
+                    Some(category) => self.jackpot(category),
+                    None => self.count_losing_spin(),
+                }
+            }
+            GameState::Jitan { .. } => {
+                let result = self.lottery.lottery_normal();
+                let category = self.decide_jackpot(result);
+                self.output.lottery_normal(result);
+                self.emit_lottery(LotteryMode::Normal, result);
+
+                match category {
+                    Some(category) => self.jackpot(category),
+                    None => {
+                        let before = self.state;
+                        self.state.consume_jitan_spin();
+                        self.emit_mode_events(before);
+                    }
+                }
+            }
+            GameState::Kakuhen { .. } => {
+                let result = self.lottery.lottery_kakuhen();
+                let category = self.decide_jackpot(result);
+                self.output.lottery_kakuhen(result);
+                self.emit_lottery(LotteryMode::Kakuhen, result);
+
+                match category {
+                    Some(category) => self.jackpot(category),
+                    None => {
+                        if self.lottery.lottery_kakuhen_fall() {
+                            let before = self.state;
+                            self.state.fall();
+                            self.emit_mode_events(before);
+                        }
+                    }
+                }
+            }
+            GameState::Rush { n, .. } => {
+                let result = self.lottery.lottery_rush();
+                self.output.lottery_rush(result);
+                self.emit_lottery(LotteryMode::Rush, result);
+
+                if result.is_win() {
+                    self.continue_rush(n);
+                }
+            }
+            GameState::SpecialTimes { .. } => {
+                let result = self.lottery.lottery_rush();
+                self.output.lottery_rush(result);
+                self.emit_lottery(LotteryMode::Rush, result);
+
+                let before = self.state;
+                if result.is_win() {
+                    self.state.trigger_rush(&self.config);
+                    self.emit_mode_events(before);
+                    self.award(true);
+                } else {
+                    self.state.consume_special_times_spin(&self.config);
+                    self.emit_mode_events(before);
+                }
+            }
+        }
+    }
+
+    /// Counts a losing spin in normal mode and fires the ceiling once it is reached.
+    fn count_losing_spin(&mut self) {
+        let Some(spins) = self.state.count_losing_spin() else {
+            return;
+        };
+        let Some(ceiling) = self.config.ceiling else {
             return;
         };
 
-        // When rush
+        if spins >= ceiling.spins {
+            let before = self.state;
+            self.state.trigger_ceiling(&ceiling, &self.config);
+            self.output.ceiling(ceiling);
+            self.emit(GameEvent::CeilingReached(ceiling));
+            self.emit_mode_events(before);
+        }
+    }
 
+    /// Decides the category of a jackpot won outside rush mode and reports it.
+    ///
+    /// The category is reported before the lottery result, so that the presentation
+    /// of the win can show it.
+    fn decide_jackpot(&mut self, result: LotteryResult) -> Option<JackpotCategory> {
+        if !result.is_win() {
+            return None;
+        }
+
+        let category = self.lottery.lottery_jackpot_category();
+        self.output.jackpot_category(category);
+        self.emit(GameEvent::JackpotDecided(category));
+        Some(category)
+    }
+
+    /// Routes a jackpot won outside rush mode into kakuhen or rush mode.
+    fn jackpot(&mut self, category: JackpotCategory) {
+        let before = self.state;
+        match category {
+            JackpotCategory::Kakuhen => self.state.trigger_kakuhen(),
+            JackpotCategory::Rush => self.state.trigger_rush(&self.config),
+            JackpotCategory::Normal => self.state.miss_rush(&self.config),
+        }
+        self.emit_mode_events(before);
+        self.award(false);
+    }
+
+    /// Pays out a jackpot.
+    ///
+    /// Without `Config::payout` the jackpot adds `incremental_balls` at once. Otherwise
+    /// a bonus starts, with its rounds drawn from the rush or normal round table.
+    fn award(&mut self, in_rush: bool) {
+        match &self.payout {
+            None => {
+                self.state.increment_balls(&self.config);
+                self.emit(GameEvent::BallsAwarded {
+                    balls: self.config.incremental_balls,
+                });
+            }
+            Some(payout) => {
+                let table = if in_rush {
+                    &payout.rush
+                } else {
+                    &payout.normal
+                };
+                self.bonus = Some(Bonus {
+                    rounds: self.lottery.lottery_rounds(table),
+                    round: 1,
+                    count: 0,
+                });
+            }
+        }
+    }
+
+    /// Draws the continuation lottery after a win in the `n`-th rush round.
+    fn continue_rush(&mut self, n: usize) {
         let continue_lottery = match self.lottery.lottery_rush_continue(n) {
             Ok(lottery) => lottery,
             Err(error) => {
-                println!("Warn: {}", error);
+                self.output.probability_error(error);
+                self.emit(GameEvent::Warning(error));
+                // The rush jackpot itself was won; only its continuation is lost
+                self.award(true);
                 return;
             }
         };
 
         self.output.lottery_rush_continue(continue_lottery);
+        self.emit_lottery(LotteryMode::RushContinue, continue_lottery);
 
         if continue_lottery.is_win() {
-            self.state.trigger_rush(&self.config)
-        } else {
-            self.state.increment_balls(&self.config)
-        };
+            let before = self.state;
+            self.state.trigger_rush(&self.config);
+            self.emit_mode_events(before);
+        }
+        self.award(true);
+    }
+
+    /// Passes an event to the output.
+    fn emit(&mut self, event: GameEvent) {
+        self.output.event(event);
+    }
+
+    fn emit_lottery(&mut self, mode: LotteryMode, result: LotteryResult) {
+        self.emit(GameEvent::LotteryDrawn { mode, result });
+    }
+
+    /// Emits the mode events caused by a state change from `before`.
+    ///
+    /// The mode left is reported before the mode entered.
+    fn emit_mode_events(&mut self, before: GameState) {
+        match (before, self.state) {
+            (GameState::Kakuhen { .. }, GameState::Kakuhen { .. })
+            | (GameState::Jitan { .. }, GameState::Jitan { .. }) => {}
+            (GameState::Kakuhen { n, .. }, _) => self.emit(GameEvent::KakuhenEnded { n }),
+            (GameState::Jitan { .. }, _) => self.emit(GameEvent::JitanEnded),
+            _ => {}
+        }
+
+        match (before.rush_round(), self.state.rush_round()) {
+            (None, Some(_)) => {
+                self.rush_start_balls = before.balls();
+                self.emit(GameEvent::RushEntered);
+            }
+            (Some(n), None) => {
+                let gained = self.state.balls().saturating_sub(self.rush_start_balls);
+                self.emit(GameEvent::RushEnded { n, gained });
+            }
+            (Some(n), Some(m)) if m > n => self.emit(GameEvent::RushContinued { n: m }),
+            _ => {}
+        }
+
+        match (before, self.state) {
+            (GameState::Kakuhen { .. }, GameState::Kakuhen { .. })
+            | (GameState::Jitan { .. }, GameState::Jitan { .. }) => {}
+            (_, GameState::Kakuhen { .. }) => self.emit(GameEvent::KakuhenEntered),
+            (
+                _,
+                GameState::Jitan {
+                    remaining_spins, ..
+                },
+            ) => self.emit(GameEvent::JitanEntered {
+                spins: remaining_spins,
+            }),
+            _ => {}
+        }
     }
 
     /// Returns a reference to the current game state.
     ///
     /// # Returns
     ///
-    /// A reference to the current `GameState` (Uninitialized, Normal, or Rush).
+    /// A reference to the current `GameState` (Uninitialized, Normal, Rush, SpecialTimes,
+    /// Kakuhen, or Jitan).
     pub fn state(&self) -> &GameState {
         &self.state
     }
 
+    /// Returns a reference to the hold and spin clock.
+    ///
+    /// # Returns
+    ///
+    /// A reference to the current `Hold`.
+    pub fn hold(&self) -> &Hold {
+        &self.hold
+    }
+
+    /// Returns the jackpot being paid out, if any.
+    ///
+    /// # Returns
+    ///
+    /// `Some(&Bonus)` during a bonus, `None` otherwise.
+    pub fn bonus(&self) -> Option<&Bonus> {
+        self.bonus.as_ref()
+    }
+
     /// Returns a reference to the output handler.
     ///
     /// # Returns
@@ -1,9 +1,11 @@
-use rand::{Rng, rngs::ThreadRng};
+use rand::rngs::ThreadRng;
 
 use crate::{
-    command::Command,
-    game::{GameState, Transition},
-    lottery::LotteryResult,
+    command::{Command, RecordedCommand},
+    config::{Ceiling, RushContinueFn},
+    event::GameEvent,
+    game::{Bonus, GameError, GameState, Transition},
+    lottery::{JackpotCategory, LotteryResult, LotterySource, ProbabilityError},
 };
 
 /// Trait for handling user input in the pachislot game.
@@ -41,8 +43,11 @@ use crate::{
 ///     }
 /// }
 /// ```
-pub trait UserInput<O: UserOutput, F: FnMut(usize) -> f64 = fn(usize) -> f64, R: Rng = ThreadRng>:
-    Sized
+pub trait UserInput<
+    O: UserOutput,
+    F: RushContinueFn = fn(usize) -> f64,
+    R: LotterySource = ThreadRng,
+>: Sized
 {
     /// Waits for user input and returns a command to execute.
     ///
@@ -183,4 +188,134 @@ pub trait UserOutput {
     /// - Win results extend the rush, lose results end it
     /// - May want to show countdown or progress indicators
     fn lottery_rush_continue(&mut self, result: LotteryResult);
+
+    /// Handles kakuhen mode lottery result presentation.
+    ///
+    /// This method is called when a lottery event occurs during kakuhen
+    /// (probability variable) mode, where jackpot odds are boosted.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The `LotteryResult` containing the outcome and any visual effects
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation presents the result like a normal mode lottery
+    /// - Override it to give kakuhen mode its own presentation
+    fn lottery_kakuhen(&mut self, result: LotteryResult) {
+        self.lottery_normal(result);
+    }
+
+    /// Handles the category of a jackpot won outside rush mode.
+    ///
+    /// This method is called right before the lottery callback (`lottery_normal` or
+    /// `lottery_kakuhen`) of the winning draw, so the presentation of the win can
+    /// match where the jackpot leads, e.g. with
+    /// [`SlotProducer::produce_jackpot`](crate::slot::SlotProducer::produce_jackpot).
+    ///
+    /// # Arguments
+    ///
+    /// * `category` - The `JackpotCategory` the jackpot is routed into
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the category
+    fn jackpot_category(&mut self, _category: JackpotCategory) {}
+
+    /// Handles a probability error raised while drawing a lottery.
+    ///
+    /// This method is called when `rush_continue_fn` yields a multiplier that
+    /// makes the rush continuation probability leave the range 0.0 to 1.0 for a
+    /// rush round beyond the validated horizon. The continuation lottery is skipped
+    /// and treated as lost: the rush jackpot that led to it was won and is still paid
+    /// out (as a bonus from the rush round table with `Config::payout`), but the rush
+    /// round does not continue.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The `ProbabilityError` describing the invalid probability
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the error
+    /// - Consider logging the error, as it indicates a faulty configuration
+    fn probability_error(&mut self, _error: ProbabilityError) {}
+
+    /// Handles the end of a jackpot round.
+    ///
+    /// This method is called during a bonus (when `Config::payout` is set) each time
+    /// the attacker has taken its `counts` balls and the round ends.
+    ///
+    /// # Arguments
+    ///
+    /// * `bonus` - The `Bonus` as of the end of the round, with `round` being the finished round
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the round
+    /// - The bonus is over when `bonus.round == bonus.rounds`
+    fn bonus_round(&mut self, _bonus: Bonus) {}
+
+    /// Handles the ceiling firing.
+    ///
+    /// This method is called when `BallsConfig::ceiling` is set and the configured
+    /// number of consecutive losing spins in normal mode has been reached. The game
+    /// state has already been switched to the mode granted by the ceiling.
+    ///
+    /// # Arguments
+    ///
+    /// * `ceiling` - The `Ceiling` that fired
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the event
+    fn ceiling(&mut self, _ceiling: Ceiling) {}
+
+    /// Handles a command rejected in the current game state.
+    ///
+    /// This method is called when a command returned by the input cannot be executed,
+    /// e.g. launching a ball before the game is started. The game state is unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `error` - The `GameError` describing why the command was rejected
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the rejection
+    /// - Consider telling the player which command is available instead
+    fn command_rejected(&mut self, _error: GameError) {}
+
+    /// Handles a command received by the game loop.
+    ///
+    /// This method is called by `Game::run_step_with_command` before the command is
+    /// executed, so a session can be logged and replayed later.
+    ///
+    /// # Arguments
+    ///
+    /// * `command` - The `RecordedCommand` describing the command
+    ///   (`RecordedCommand::Quit` for `Command::FinishGame`), or `None` if it is a
+    ///   custom command without a recorded form (see `ControlCommand::recorded`)
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the command
+    fn command_received(&mut self, _command: Option<RecordedCommand>) {}
+
+    /// Handles a structured game event.
+    ///
+    /// This method is called for every `GameEvent`, in the order the events happen,
+    /// in addition to the callbacks above. It covers launches, start hole entries,
+    /// lotteries, rush entry and exit, payouts and warnings.
+    ///
+    /// # Arguments
+    ///
+    /// * `event` - The `GameEvent` that just happened
+    ///
+    /// # Implementation Notes
+    ///
+    /// - The default implementation ignores the event
+    /// - Implement [`EventHandler`](crate::event::EventHandler) instead of this trait to
+    ///   consume only the event stream
+    fn event(&mut self, _event: GameEvent) {}
 }
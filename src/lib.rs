@@ -1,22 +1,40 @@
 #![doc = include_str!("../README.md")]
 
+/// Exact expectations of a configuration via Markov-chain analysis.
+pub mod analysis;
 /// Command handling module for game control operations.
 pub mod command;
 /// Configuration module for game settings and parameters.
 pub mod config;
+/// Declarative rush continuation curves.
+pub mod curve;
+/// Structured stream of game events.
+pub mod event;
 /// Core game logic and state management.
 pub mod game;
 /// User input and output interface traits.
 pub mod interface;
 /// Lottery system for determining game outcomes.
 pub mod lottery;
+/// Odds notation (`1/319.7`, `0.5%`) for probabilities.
+pub mod odds;
+/// Reference machine specs modeled on real pachinko categories.
+pub mod presets;
+/// Session recording and deterministic replay.
+pub mod replay;
+/// Independent random number generators derived from a single seed.
+pub mod seed;
+/// Monte Carlo batch simulation with aggregated statistics.
+pub mod simulate;
 /// Slot machine visual representation and logic.
 pub mod slot;
 
 /// Re-export of the main Game struct for convenient access.
 pub use game::Game;
 
-use crate::config::{BallsConfig, Config, Probability, SlotProbability};
+use crate::config::{
+    BallsConfig, Config, DEFAULT_JITAN_START_HOLE, Probability, RushModel, SlotProbability,
+};
 
 /// Example probability value for starting hole entrance.
 ///
@@ -44,6 +62,18 @@ pub const CONFIG_EXAMPLE: Config = Config {
         incremental_balls: 15,
         // Incremental number of balls in rush mode when become or continue rush mode
         incremental_rush: 300,
+        // Maximum number of start hole entries waiting for a spin
+        hold_capacity: 4,
+        // Number of ball launches one spin lasts (0: every entry is drawn immediately)
+        spin_duration: 0,
+        // Number of jitan (time-shortening) spins after rush mode ends (0: back to normal)
+        jitan_spins: 0,
+        // Number of ball launches one spin lasts during jitan
+        jitan_spin_duration: 0,
+        // Rush lasts for a budget of rush balls and continues by a separate lottery
+        rush_model: RushModel::ContinueLottery,
+        // No ceiling after consecutive losing spins
+        ceiling: None,
     },
     // Config of Probability
     probability: Probability {
@@ -83,5 +113,30 @@ pub const CONFIG_EXAMPLE: Config = Config {
         // This function should be return 1 when n == 1
         // This function should be monotonically non-increasing
         rush_continue_fn: |n| 0.6f64.powi(n as i32 - 1),
+
+        // Number of RUSH times over which `rush_continue_fn` is checked by validation
+        rush_continue_horizon: 100,
+
+        // Probability of lottery in kakuhen mode
+        kakuhen: SlotProbability {
+            win: 0.4,
+            fake_win: 0.3,
+            fake_lose: 0.15,
+        },
+
+        // Share of jackpots routed into kakuhen mode instead of rush mode (0: kakuhen disabled)
+        kakuhen_entry: 0.0,
+
+        // Probability that a losing kakuhen spin falls back to normal mode
+        kakuhen_fall: 0.0,
+
+        // Probability that the electric tulip guides a missed ball into the start hole in jitan
+        jitan_start_hole: DEFAULT_JITAN_START_HOLE,
+
+        // Probability that a jackpot outside rush enters rush mode
+        rush_entry: 1.0,
     },
+
+    // Jackpots add incremental_balls at once instead of being paid out in rounds
+    payout: None,
 };
@@ -1,18 +1,21 @@
-use std::fmt::Display;
+use std::{collections::VecDeque, error::Error, fmt::Display};
 
 use rand::{Rng, rngs::ThreadRng};
 
-use crate::config::{Probability, SlotProbability};
+use crate::{
+    config::{Probability, RoundWeight, RushContinueFn, SlotProbability},
+    event::LotteryMode,
+};
 
 /// Lottery system that manages probability-based outcomes for the pachislot game.
 ///
 /// This struct handles all lottery operations including normal mode, rush mode,
-/// and rush continuation calculations. It uses a random number generator to
-/// determine outcomes based on configured probabilities.
+/// and rush continuation calculations. It draws the outcomes from a [`LotterySource`],
+/// usually a random number generator, with the configured probabilities.
 ///
 /// # Type Parameters
 ///
-/// * `R` - Random number generator type implementing the `Rng` trait (defaults to `ThreadRng`)
+/// * `R` - Source of outcomes implementing `LotterySource`, e.g. any `Rng` (defaults to `ThreadRng`)
 ///
 /// # Examples
 ///
@@ -24,7 +27,7 @@ use crate::config::{Probability, SlotProbability};
 /// let mut lottery = Lottery::new(probability_config);
 /// let result = lottery.lottery_normal();
 /// ```
-pub struct Lottery<F: FnMut(usize) -> f64 = fn(usize) -> f64, R: Rng = ThreadRng> {
+pub struct Lottery<F: RushContinueFn = fn(usize) -> f64, R: LotterySource = ThreadRng> {
     rng: R,
     probability: Probability<F>,
 }
@@ -34,7 +37,9 @@ pub struct Lottery<F: FnMut(usize) -> f64 = fn(usize) -> f64, R: Rng = ThreadRng
 /// This enum represents the outcome of any lottery draw, which can be either
 /// a win or a loss. Each outcome can have additional visual effects (fake results)
 /// to enhance gameplay drama and suspense.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum LotteryResult {
     /// A winning lottery result with possible visual effects.
     Win(Win),
@@ -46,7 +51,9 @@ pub enum LotteryResult {
 ///
 /// This enum distinguishes between different types of wins that can occur,
 /// affecting how the result is presented to the player.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Win {
     /// A standard win with normal visual presentation.
     Default,
@@ -59,7 +66,9 @@ pub enum Win {
 ///
 /// This enum distinguishes between different types of losses that can occur,
 /// affecting how the result is presented to the player.
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Lose {
     /// A standard loss with normal visual presentation.
     Default,
@@ -68,6 +77,22 @@ pub enum Lose {
     FakeLose,
 }
 
+/// Route of a jackpot won outside rush mode, decided together with the win.
+///
+/// Machines often show it through the winning symbol, e.g. odd numbers for a rush;
+/// see [`SlotProducer::with_categories`](crate::slot::SlotProducer::with_categories).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum JackpotCategory {
+    /// The jackpot enters (or continues) kakuhen mode.
+    Kakuhen,
+    /// The jackpot enters rush mode.
+    Rush,
+    /// Normal bonus (通常大当たり): the jackpot pays out and continues in jitan or normal mode.
+    Normal,
+}
+
 impl LotteryResult {
     /// Checks if this lottery result represents a win.
     ///
@@ -88,7 +113,7 @@ impl LotteryResult {
     }
 }
 
-impl<F: FnMut(usize) -> f64, R: Rng + Default> Lottery<F, R> {
+impl<F: RushContinueFn, R: LotterySource + Default> Lottery<F, R> {
     /// Creates a new Lottery instance with default random number generator.
     ///
     /// # Arguments
@@ -115,7 +140,7 @@ impl<F: FnMut(usize) -> f64, R: Rng + Default> Lottery<F, R> {
     }
 }
 
-impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
+impl<F: RushContinueFn, R: LotterySource> Lottery<F, R> {
     /// Creates a new Lottery instance with a custom random number generator.
     ///
     /// This method is useful for testing or when you need deterministic behavior
@@ -124,7 +149,7 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
     /// # Arguments
     ///
     /// * `probability` - Probability configuration for all lottery modes
-    /// * `rng` - Custom random number generator implementing `Rng`
+    /// * `rng` - Custom random number generator, or another `LotterySource`
     ///
     /// # Returns
     ///
@@ -133,41 +158,30 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
         Self { rng, probability }
     }
 
+    /// Returns the probability configuration.
+    pub fn probability(&self) -> &Probability<F> {
+        &self.probability
+    }
+
+    /// Returns the random number generator (or other source) in its current state.
+    pub fn rng(&self) -> &R {
+        &self.rng
+    }
+
     /// Performs a lottery draw with the specified probability configuration.
     ///
-    /// This is the core lottery method that determines outcomes based on
-    /// win probability and visual effect probabilities.
+    /// This is the core lottery method; the outcome is drawn from the source.
     ///
     /// # Arguments
     ///
+    /// * `mode` - Kind of the lottery
     /// * `probability` - Slot probability configuration containing win, fake_win, and fake_lose rates
     ///
     /// # Returns
     ///
     /// A `LotteryResult` indicating the outcome and any visual effects.
-    ///
-    /// # Algorithm
-    ///
-    /// 1. First determines if the outcome is a win or loss based on `probability.win`
-    /// 2. If win: applies `probability.fake_win` chance for FakeWin effect
-    /// 3. If loss: applies `probability.fake_lose` chance for FakeLose effect
-    pub fn lottery(&mut self, probability: SlotProbability) -> LotteryResult {
-        let SlotProbability {
-            win,
-            fake_win,
-            fake_lose,
-        } = probability;
-
-        match self.rng.random_bool(win) {
-            true => match self.rng.random_bool(fake_win) {
-                true => LotteryResult::Win(Win::FakeWin),
-                false => LotteryResult::Win(Win::Default),
-            },
-            false => match self.rng.random_bool(fake_lose) {
-                true => LotteryResult::Lose(Lose::FakeLose),
-                false => LotteryResult::Lose(Lose::Default),
-            },
-        }
+    pub fn lottery(&mut self, mode: LotteryMode, probability: SlotProbability) -> LotteryResult {
+        self.rng.draw(mode, probability)
     }
 
     /// Performs a lottery draw using normal mode probabilities.
@@ -178,7 +192,7 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
     ///
     /// A `LotteryResult` based on normal mode probability settings.
     pub fn lottery_normal(&mut self) -> LotteryResult {
-        self.lottery(self.probability.normal)
+        self.lottery(LotteryMode::Normal, self.probability.normal)
     }
 
     /// Performs a lottery draw using rush mode probabilities.
@@ -190,7 +204,96 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
     ///
     /// A `LotteryResult` based on rush mode probability settings.
     pub fn lottery_rush(&mut self) -> LotteryResult {
-        self.lottery(self.probability.rush)
+        self.lottery(LotteryMode::Rush, self.probability.rush)
+    }
+
+    /// Performs a lottery draw using kakuhen mode probabilities.
+    ///
+    /// # Returns
+    ///
+    /// A `LotteryResult` based on kakuhen mode probability settings.
+    pub fn lottery_kakuhen(&mut self) -> LotteryResult {
+        self.lottery(LotteryMode::Kakuhen, self.probability.kakuhen)
+    }
+
+    /// Decides whether a jackpot is routed into kakuhen mode.
+    ///
+    /// # Returns
+    ///
+    /// `true` with probability `kakuhen_entry`, in which case the jackpot enters
+    /// (or continues) kakuhen mode instead of rush mode.
+    pub fn lottery_kakuhen_entry(&mut self) -> bool {
+        self.decide(self.probability.kakuhen_entry)
+    }
+
+    /// Decides whether a jackpot that is not routed into kakuhen mode enters rush mode.
+    ///
+    /// # Returns
+    ///
+    /// `true` with probability `rush_entry`.
+    pub fn lottery_rush_entry(&mut self) -> bool {
+        self.decide(self.probability.rush_entry)
+    }
+
+    /// Decides the category of a jackpot won outside rush mode.
+    ///
+    /// # Returns
+    ///
+    /// `Kakuhen` with probability `kakuhen_entry`, otherwise `Rush` with probability
+    /// `rush_entry`, otherwise `Normal`.
+    pub fn lottery_jackpot_category(&mut self) -> JackpotCategory {
+        let Probability {
+            kakuhen_entry,
+            rush_entry,
+            ..
+        } = self.probability;
+
+        // Like `decide`, certain categories are decided without asking the source
+        if kakuhen_entry >= 1.0 {
+            JackpotCategory::Kakuhen
+        } else if kakuhen_entry > 0.0 || (rush_entry > 0.0 && rush_entry < 1.0) {
+            self.rng.category(kakuhen_entry, rush_entry)
+        } else if rush_entry >= 1.0 {
+            JackpotCategory::Rush
+        } else {
+            JackpotCategory::Normal
+        }
+    }
+
+    /// Decides whether a losing kakuhen spin falls back to normal mode.
+    ///
+    /// # Returns
+    ///
+    /// `true` with probability `kakuhen_fall`.
+    pub fn lottery_kakuhen_fall(&mut self) -> bool {
+        self.decide(self.probability.kakuhen_fall)
+    }
+
+    /// Decides whether the electric tulip guides a missed ball into the start hole during jitan.
+    ///
+    /// # Returns
+    ///
+    /// `true` with probability `jitan_start_hole`.
+    pub fn lottery_jitan_start_hole(&mut self) -> bool {
+        self.decide(self.probability.jitan_start_hole)
+    }
+
+    /// Decides whether an event with the given probability happens, see [`decide`].
+    fn decide(&mut self, probability: f64) -> bool {
+        decide(&mut self.rng, probability)
+    }
+
+    /// Draws the number of rounds of a jackpot from a weighted round table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Round table with at least one entry of positive weight
+    ///
+    /// # Returns
+    ///
+    /// The `rounds` of the chosen entry, each entry being chosen in proportion to its weight.
+    pub fn lottery_rounds(&mut self, table: &[RoundWeight]) -> usize {
+        self.rng.rounds(table)
     }
 
     /// Performs a lottery draw to determine rush mode continuation.
@@ -206,7 +309,7 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
     /// # Returns
     ///
     /// * `Ok(LotteryResult)` - The lottery result for rush continuation
-    /// * `Err(ProbabilityError)` - If the calculated probability exceeds 1.0
+    /// * `Err(ProbabilityError)` - If the calculated probability is outside the range 0.0 to 1.0
     ///
     /// # Examples
     ///
@@ -220,22 +323,299 @@ impl<F: FnMut(usize) -> f64, R: Rng> Lottery<F, R> {
     pub fn lottery_rush_continue(&mut self, n: usize) -> Result<LotteryResult, ProbabilityError> {
         let mut probability = self.probability.rush_continue;
 
-        probability.win *= (self.probability.rush_continue_fn)(n);
+        probability.win *= self.probability.rush_continue_fn.evaluate(n);
 
-        if probability.win > 1.0 {
+        if !(0.0..=1.0).contains(&probability.win) {
             return Err(ProbabilityError);
         }
 
-        Ok(self.lottery(probability))
+        Ok(self.lottery(LotteryMode::RushContinue, probability))
+    }
+}
+
+/// Source of the outcomes a [`Lottery`] draws.
+///
+/// Every `Rng` is a source drawing at random with the given probabilities.
+/// [`ScriptedLottery`] plays back fixed results instead, for deterministic scenario tests.
+pub trait LotterySource {
+    /// Draws the result of a slot lottery.
+    ///
+    /// # Arguments
+    ///
+    /// * `mode` - Kind of the lottery
+    /// * `probability` - Probabilities of the lottery; for `RushContinue` the win
+    ///   probability is already scaled by `rush_continue_fn`
+    ///
+    /// # Returns
+    ///
+    /// A `LotteryResult` indicating the outcome and any visual effects.
+    fn draw(&mut self, mode: LotteryMode, probability: SlotProbability) -> LotteryResult;
+
+    /// Decides whether an event with the given probability happens.
+    ///
+    /// Used for kakuhen entry, rush entry, kakuhen fall and jitan start hole assists.
+    /// [`Lottery`] only asks for probabilities strictly between 0.0 and 1.0.
+    fn decide(&mut self, probability: f64) -> bool;
+
+    /// Decides the category of a jackpot won outside rush mode.
+    ///
+    /// [`Lottery`] only asks when the category is not certain. The default
+    /// implementation decides the kakuhen entry first and then the rush entry.
+    ///
+    /// # Arguments
+    ///
+    /// * `kakuhen_entry` - Probability of `Kakuhen`
+    /// * `rush_entry` - Probability of `Rush` when the jackpot is not routed into kakuhen mode
+    fn category(&mut self, kakuhen_entry: f64, rush_entry: f64) -> JackpotCategory {
+        if decide(self, kakuhen_entry) {
+            JackpotCategory::Kakuhen
+        } else if decide(self, rush_entry) {
+            JackpotCategory::Rush
+        } else {
+            JackpotCategory::Normal
+        }
+    }
+
+    /// Draws the number of rounds of a jackpot from a weighted round table.
+    ///
+    /// # Arguments
+    ///
+    /// * `table` - Round table with at least one entry of positive weight
+    fn rounds(&mut self, table: &[RoundWeight]) -> usize;
+}
+
+impl<R: Rng> LotterySource for R {
+    /// Draws a win or loss based on `probability.win`, then applies the
+    /// `probability.fake_win` or `probability.fake_lose` chance for a fake effect.
+    fn draw(&mut self, _mode: LotteryMode, probability: SlotProbability) -> LotteryResult {
+        let SlotProbability {
+            win,
+            fake_win,
+            fake_lose,
+        } = probability;
+
+        match self.random_bool(win) {
+            true => match self.random_bool(fake_win) {
+                true => LotteryResult::Win(Win::FakeWin),
+                false => LotteryResult::Win(Win::Default),
+            },
+            false => match self.random_bool(fake_lose) {
+                true => LotteryResult::Lose(Lose::FakeLose),
+                false => LotteryResult::Lose(Lose::Default),
+            },
+        }
+    }
+
+    fn decide(&mut self, probability: f64) -> bool {
+        self.random_bool(probability)
+    }
+
+    /// Chooses each entry in proportion to its weight.
+    fn rounds(&mut self, table: &[RoundWeight]) -> usize {
+        let total: u64 = table.iter().map(|entry| u64::from(entry.weight)).sum();
+        let mut ticket = self.random_range(0..total);
+        for entry in table {
+            let weight = u64::from(entry.weight);
+            if ticket < weight {
+                return entry.rounds;
+            }
+            ticket -= weight;
+        }
+        unreachable!()
+    }
+}
+
+/// Decides whether an event with the given probability happens.
+///
+/// Certain outcomes (0.0 and 1.0) are decided without asking the source, so
+/// mechanics a configuration does not use leave the random number stream untouched.
+fn decide<S: LotterySource + ?Sized>(source: &mut S, probability: f64) -> bool {
+    if probability <= 0.0 {
+        false
+    } else if probability >= 1.0 {
+        true
+    } else {
+        source.decide(probability)
+    }
+}
+
+/// Lottery source playing back scripted results, for deterministic scenario tests.
+///
+/// Each kind of lottery takes the next result of its own script. Decisions the
+/// configuration leaves to chance take the next entry of the decision script (kakuhen
+/// entry, rush entry, kakuhen fall and jitan start hole assists), the jackpot
+/// category script or the round script. Certain outcomes, such as a rush entry of 1.0
+/// or a round table with a single entry of positive weight, are taken without a script.
+///
+/// # Panics
+///
+/// A lottery or decision panics when its script has run out, naming the script,
+/// so a scenario never silently continues with made-up results.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     event::LotteryMode,
+///     lottery::{Lose, LotteryResult, ScriptedLottery, Win},
+/// };
+///
+/// const WIN: LotteryResult = LotteryResult::Win(Win::Default);
+/// const LOSE: LotteryResult = LotteryResult::Lose(Lose::Default);
+///
+/// // Jackpot, then two continuations before the rush fails
+/// let script = ScriptedLottery::new()
+///     .normal([WIN])
+///     .rush([WIN, WIN, WIN])
+///     .rush_continue([WIN, WIN, LOSE]);
+/// assert_eq!(script.remaining(LotteryMode::Rush), 3);
+/// ```
+///
+/// Pass the script to [`Game::with_rng`](crate::Game::with_rng) or
+/// [`GameBuilder::with_rng`](crate::game::GameBuilder::with_rng) in place of a random
+/// number generator.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ScriptedLottery {
+    normal: VecDeque<LotteryResult>,
+    rush: VecDeque<LotteryResult>,
+    rush_continue: VecDeque<LotteryResult>,
+    kakuhen: VecDeque<LotteryResult>,
+    decisions: VecDeque<bool>,
+    categories: VecDeque<JackpotCategory>,
+    rounds: VecDeque<usize>,
+}
+
+impl ScriptedLottery {
+    /// Creates a source with empty scripts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends results to the script of normal mode (and jitan) lotteries.
+    pub fn normal(mut self, results: impl IntoIterator<Item = LotteryResult>) -> Self {
+        self.normal.extend(results);
+        self
+    }
+
+    /// Appends results to the script of rush mode (and ST rush) lotteries.
+    pub fn rush(mut self, results: impl IntoIterator<Item = LotteryResult>) -> Self {
+        self.rush.extend(results);
+        self
+    }
+
+    /// Appends results to the script of rush continuation lotteries.
+    pub fn rush_continue(mut self, results: impl IntoIterator<Item = LotteryResult>) -> Self {
+        self.rush_continue.extend(results);
+        self
+    }
+
+    /// Appends results to the script of kakuhen mode lotteries.
+    pub fn kakuhen(mut self, results: impl IntoIterator<Item = LotteryResult>) -> Self {
+        self.kakuhen.extend(results);
+        self
+    }
+
+    /// Appends outcomes to the script of decisions left to chance.
+    ///
+    /// Kakuhen entry, rush entry, kakuhen fall and jitan start hole assists share this
+    /// script, in the order the game asks for them.
+    pub fn decisions(mut self, decisions: impl IntoIterator<Item = bool>) -> Self {
+        self.decisions.extend(decisions);
+        self
+    }
+
+    /// Appends categories to the script of jackpots won outside rush mode.
+    pub fn categories(mut self, categories: impl IntoIterator<Item = JackpotCategory>) -> Self {
+        self.categories.extend(categories);
+        self
+    }
+
+    /// Appends numbers of rounds to the script of jackpot payouts.
+    ///
+    /// Each number must be the `rounds` of an entry of positive weight in the round
+    /// table it is drawn from.
+    pub fn rounds(mut self, rounds: impl IntoIterator<Item = usize>) -> Self {
+        self.rounds.extend(rounds);
+        self
+    }
+
+    /// Returns the number of results left in the script of `mode`.
+    pub fn remaining(&self, mode: LotteryMode) -> usize {
+        self.script(mode).len()
+    }
+
+    /// Checks whether every script, including decisions, categories and rounds, is used up.
+    pub fn is_exhausted(&self) -> bool {
+        self.normal.is_empty()
+            && self.rush.is_empty()
+            && self.rush_continue.is_empty()
+            && self.kakuhen.is_empty()
+            && self.decisions.is_empty()
+            && self.categories.is_empty()
+            && self.rounds.is_empty()
+    }
+
+    fn script(&self, mode: LotteryMode) -> &VecDeque<LotteryResult> {
+        match mode {
+            LotteryMode::Normal => &self.normal,
+            LotteryMode::Rush => &self.rush,
+            LotteryMode::RushContinue => &self.rush_continue,
+            LotteryMode::Kakuhen => &self.kakuhen,
+        }
+    }
+}
+
+impl LotterySource for ScriptedLottery {
+    fn draw(&mut self, mode: LotteryMode, _probability: SlotProbability) -> LotteryResult {
+        let script = match mode {
+            LotteryMode::Normal => &mut self.normal,
+            LotteryMode::Rush => &mut self.rush,
+            LotteryMode::RushContinue => &mut self.rush_continue,
+            LotteryMode::Kakuhen => &mut self.kakuhen,
+        };
+        script
+            .pop_front()
+            .unwrap_or_else(|| panic!("{mode:?} lottery script exhausted"))
+    }
+
+    fn decide(&mut self, probability: f64) -> bool {
+        self.decisions
+            .pop_front()
+            .unwrap_or_else(|| panic!("Decision script exhausted (probability {probability})"))
+    }
+
+    fn category(&mut self, _kakuhen_entry: f64, _rush_entry: f64) -> JackpotCategory {
+        self.categories
+            .pop_front()
+            .expect("Jackpot category script exhausted")
+    }
+
+    fn rounds(&mut self, table: &[RoundWeight]) -> usize {
+        let mut entries = table.iter().filter(|entry| entry.weight > 0);
+        if let (Some(entry), None) = (entries.next(), entries.next()) {
+            return entry.rounds;
+        }
+
+        let rounds = self.rounds.pop_front().expect("Round script exhausted");
+        assert!(
+            table
+                .iter()
+                .any(|entry| entry.weight > 0 && entry.rounds == rounds),
+            "Scripted {rounds} rounds are not in the round table"
+        );
+        rounds
     }
 }
 
 /// Error indicating that a probability calculation resulted in an invalid value.
 ///
-/// This error occurs when probability calculations exceed the valid range of 0.0 to 1.0,
+/// This error occurs when probability calculations leave the valid range of 0.0 to 1.0,
 /// most commonly in the `lottery_rush_continue` function when the `rush_continue_fn`
-/// returns a value that causes the final probability to exceed 1.0.
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+/// returns such a value for an `n` beyond `rush_continue_horizon`, where validation
+/// could not catch it.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ProbabilityError;
 
 impl Display for ProbabilityError {
@@ -246,3 +626,5 @@ impl Display for ProbabilityError {
         )
     }
 }
+
+impl Error for ProbabilityError {}
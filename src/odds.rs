@@ -0,0 +1,139 @@
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// A probability written in the notation machine designers use.
+///
+/// Odds parse from `"1/N"` (`"1/319.7"`, `"1/99"`), from a percentage (`"0.5%"`) or from
+/// a plain probability (`"0.003"`). They display in both the `1/N` and the percentage
+/// form, e.g. `1/319.7 (0.31%)`.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::odds::Odds;
+///
+/// let odds: Odds = "1/319.7".parse().unwrap();
+/// assert!((odds.0 - 1.0 / 319.7).abs() < 1e-15);
+/// assert_eq!(odds.to_string(), "1/319.7 (0.31%)");
+///
+/// let odds: Odds = "80%".parse().unwrap();
+/// assert_eq!(odds.0, 0.8);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+pub struct Odds(pub f64);
+
+impl Odds {
+    /// Returns the `N` of `1/N`, or infinity for a probability of 0.
+    pub fn denominator(&self) -> f64 {
+        1.0 / self.0
+    }
+
+    /// Returns the probability in percent.
+    pub fn percent(&self) -> f64 {
+        self.0 * 100.0
+    }
+}
+
+impl From<f64> for Odds {
+    fn from(probability: f64) -> Self {
+        Self(probability)
+    }
+}
+
+impl Display for Odds {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.0 > 0.0 {
+            write!(f, "1/{:.1} ({:.2}%)", self.denominator(), self.percent())
+        } else {
+            write!(f, "never ({:.2}%)", self.percent())
+        }
+    }
+}
+
+impl FromStr for Odds {
+    type Err = ParseOddsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = |reason| ParseOddsError {
+            input: s.to_string(),
+            reason,
+        };
+        let number = |text: &str| {
+            text.trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| error("expected \"1/N\", \"P%\" or a probability"))
+        };
+
+        let text = s.trim();
+        let probability = if let Some((numerator, denominator)) = text.split_once('/') {
+            let denominator = number(denominator)?;
+            if denominator <= 0.0 {
+                return Err(error("denominator must be greater than 0"));
+            }
+            number(numerator)? / denominator
+        } else if let Some(percent) = text.strip_suffix('%') {
+            number(percent)? / 100.0
+        } else {
+            number(text)?
+        };
+
+        if (0.0..=1.0).contains(&probability) {
+            Ok(Self(probability))
+        } else {
+            Err(error("probability must be between 0.0 and 1.0"))
+        }
+    }
+}
+
+/// Error returned when a string is not valid odds notation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseOddsError {
+    /// The rejected input.
+    input: String,
+    /// Why the input was rejected.
+    reason: &'static str,
+}
+
+impl Display for ParseOddsError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid odds {:?}: {}", self.input, self.reason)
+    }
+}
+
+impl Error for ParseOddsError {}
+
+/// Deserializes a probability given either as a number or in odds notation.
+#[cfg(feature = "serde")]
+pub(crate) fn deserialize_probability<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct ProbabilityVisitor;
+
+    impl serde::de::Visitor<'_> for ProbabilityVisitor {
+        type Value = f64;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("a probability or odds such as \"1/319.7\" or \"50%\"")
+        }
+
+        fn visit_f64<E: serde::de::Error>(self, value: f64) -> Result<f64, E> {
+            Ok(value)
+        }
+
+        fn visit_i64<E: serde::de::Error>(self, value: i64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_u64<E: serde::de::Error>(self, value: u64) -> Result<f64, E> {
+            Ok(value as f64)
+        }
+
+        fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<f64, E> {
+            value.parse::<Odds>().map(|odds| odds.0).map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_any(ProbabilityVisitor)
+}
@@ -0,0 +1,179 @@
+use crate::config::{
+    BallsConfig, Config, Payout, Probability, RoundWeight, RushModel, SlotProbability,
+};
+
+/// Start hole probability shared by every preset.
+///
+/// About 18 spins per 250 balls (1000 yen), a typical rotation rate of a fair machine.
+pub const START_HOLE_PROBABILITY: f64 = 0.072;
+
+/// A reference machine spec modeled on a real pachinko category.
+///
+/// Every preset uses an ST rush and pays jackpots out in rounds. Hold and jitan are
+/// disabled, so presets can be both simulated and passed to
+/// [`analyze`](crate::analysis::analyze).
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{analysis::analyze, presets};
+///
+/// let preset = presets::middle();
+/// assert_eq!(preset.name, "middle");
+///
+/// let analysis = analyze(preset.config, preset.start_hole_probability).unwrap();
+/// assert!(analysis.payout_rate > 0.0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Preset {
+    /// Name of the category.
+    pub name: &'static str,
+    /// Nominal jackpot odds in normal mode, as the `x` of `1/x`.
+    pub nominal_odds: f64,
+    /// Share of normal mode jackpots entering rush (RUSH突入率).
+    pub rush_entry: f64,
+    /// Nominal probability that a rush continues after a jackpot (継続率).
+    pub continuation: f64,
+    /// Probability (0.0 to 1.0) that a launched ball enters the start hole.
+    pub start_hole_probability: f64,
+    /// Machine configuration.
+    pub config: Config,
+}
+
+/// Returns every preset, from the sweetest to the hardest odds.
+pub fn all() -> Vec<Preset> {
+    vec![amadeji(), light_middle(), middle(), max_type()]
+}
+
+/// Amadeji (甘デジ): jackpots at about 1/49 with short, frequent rushes.
+pub fn amadeji() -> Preset {
+    spec(Spec {
+        name: "amadeji",
+        nominal_odds: 49.9,
+        rush_odds: 12.0,
+        spins: 16,
+        rush_entry: 0.5,
+        continuation: 0.75,
+        payout: Payout {
+            normal: vec![round(3, 1)],
+            rush: vec![round(5, 1), round(3, 1)],
+            counts: 10,
+            balls_per_count: 10,
+        },
+    })
+}
+
+/// Light-middle (ライトミドル): jackpots at about 1/99.
+pub fn light_middle() -> Preset {
+    spec(Spec {
+        name: "light_middle",
+        nominal_odds: 99.9,
+        rush_odds: 40.0,
+        spins: 55,
+        rush_entry: 0.6,
+        continuation: 0.75,
+        payout: Payout {
+            normal: vec![round(3, 1)],
+            rush: vec![round(7, 1), round(3, 1)],
+            counts: 10,
+            balls_per_count: 15,
+        },
+    })
+}
+
+/// Middle (ミドル): jackpots at about 1/199, the most common category.
+pub fn middle() -> Preset {
+    spec(Spec {
+        name: "middle",
+        nominal_odds: 199.8,
+        rush_odds: 80.0,
+        spins: 130,
+        rush_entry: 0.5,
+        continuation: 0.81,
+        payout: Payout {
+            normal: vec![round(3, 1), round(10, 1)],
+            rush: vec![round(10, 7), round(4, 3)],
+            counts: 10,
+            balls_per_count: 15,
+        },
+    })
+}
+
+/// Max-type (MAXタイプ): jackpots at about 1/319 with long rushes.
+pub fn max_type() -> Preset {
+    spec(Spec {
+        name: "max_type",
+        nominal_odds: 319.7,
+        rush_odds: 99.4,
+        spins: 163,
+        rush_entry: 0.5,
+        continuation: 0.81,
+        payout: Payout {
+            normal: vec![round(3, 1), round(10, 1)],
+            rush: vec![round(10, 1)],
+            counts: 10,
+            balls_per_count: 15,
+        },
+    })
+}
+
+/// Parameters distinguishing the presets.
+struct Spec {
+    name: &'static str,
+    nominal_odds: f64,
+    rush_odds: f64,
+    spins: usize,
+    rush_entry: f64,
+    continuation: f64,
+    payout: Payout,
+}
+
+fn round(rounds: usize, weight: u32) -> RoundWeight {
+    RoundWeight { rounds, weight }
+}
+
+fn spec(spec: Spec) -> Preset {
+    let config: Config = Config {
+        balls: BallsConfig {
+            init_balls: 1000,
+            // Paid out by the payout tables instead
+            incremental_balls: 0,
+            incremental_rush: 0,
+            hold_capacity: 0,
+            spin_duration: 0,
+            jitan_spins: 0,
+            jitan_spin_duration: 0,
+            rush_model: RushModel::SpecialTimes { spins: spec.spins },
+            ceiling: None,
+        },
+        probability: Probability {
+            rush_continue_horizon: 1,
+            rush_entry: spec.rush_entry,
+            ..Probability::new(
+                SlotProbability {
+                    win: 1.0 / spec.nominal_odds,
+                    fake_win: 0.3,
+                    fake_lose: 0.15,
+                },
+                SlotProbability {
+                    win: 1.0 / spec.rush_odds,
+                    fake_win: 0.2,
+                    fake_lose: 0.05,
+                },
+                // ST rushes have no continuation lottery
+                SlotProbability::default(),
+                |_| 1.0,
+            )
+        },
+        payout: Some(spec.payout),
+    };
+
+    Preset {
+        name: spec.name,
+        nominal_odds: spec.nominal_odds,
+        rush_entry: spec.rush_entry,
+        continuation: spec.continuation,
+        start_hole_probability: START_HOLE_PROBABILITY,
+        config,
+    }
+}
@@ -0,0 +1,456 @@
+use std::{cell::RefCell, error::Error, fmt::Display, rc::Rc};
+
+use crate::{
+    command::{Command, RecordedCommand},
+    config::{Ceiling, Config, ConfigError, RushContinueFn},
+    event::{GameEvent, LotteryMode},
+    game::{Bonus, Game, GameError, GameSnapshot, GameState, Hold, Transition},
+    interface::{UserInput, UserOutput},
+    lottery::{JackpotCategory, LotteryResult, LotterySource, ProbabilityError},
+};
+
+/// A single entry of a [`SessionLog`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum LogEntry {
+    /// A command received by the game loop.
+    Command(RecordedCommand),
+    /// A custom command without a recorded form (see [`ControlCommand::recorded`]).
+    ///
+    /// The game executed the command, but a log containing it cannot be replayed.
+    ///
+    /// [`ControlCommand::recorded`]: crate::command::ControlCommand::recorded
+    Unrecordable,
+    /// A lottery drawn by the game.
+    Lottery {
+        /// Kind of the lottery.
+        mode: LotteryMode,
+        /// Outcome of the lottery.
+        result: LotteryResult,
+    },
+    /// A state transition reported to `UserOutput::default`.
+    Transition(Transition),
+}
+
+/// Position a recorded game started from, when it did not start fresh.
+///
+/// Holds the game state of a [`GameSnapshot`] without its configuration and RNG,
+/// which are passed to the [`Replayer`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct StartPosition {
+    /// Game state.
+    pub state: GameState,
+    /// Game state before the last command, reported as `Transition::before`.
+    pub before_state: Option<GameState>,
+    /// Pending start hole entries and spin clock.
+    pub hold: Hold,
+    /// Jackpot being paid out, if any.
+    pub bonus: Option<Bonus>,
+    /// Balls when the current rush was entered.
+    pub rush_start_balls: usize,
+}
+
+impl StartPosition {
+    /// Returns the position a game resumed from `snapshot` starts at.
+    pub fn of<F: RushContinueFn, R>(snapshot: &GameSnapshot<F, R>) -> Self {
+        Self {
+            state: snapshot.state,
+            before_state: snapshot.before_state,
+            hold: snapshot.hold,
+            bonus: snapshot.bonus,
+            rush_start_balls: snapshot.rush_start_balls,
+        }
+    }
+
+    /// Combines the position with a configuration and RNG into a snapshot to restore.
+    fn snapshot<F: RushContinueFn, R>(self, config: Config<F>, rng: R) -> GameSnapshot<F, R> {
+        GameSnapshot {
+            config,
+            state: self.state,
+            before_state: self.before_state,
+            hold: self.hold,
+            bonus: self.bonus,
+            rush_start_balls: self.rush_start_balls,
+            rng,
+        }
+    }
+}
+
+/// Recorded session: the commands fed to a game, the lottery outcomes they produced and
+/// the state transitions reported to the output, in order.
+///
+/// Replaying the commands with the same configuration, lottery seed and starting
+/// position reproduces every entry exactly; see [`Replayer`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SessionLog {
+    /// Position the game started from, or `None` for a fresh (uninitialized) game.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub start: Option<StartPosition>,
+    /// Entries in the order they happened.
+    pub entries: Vec<LogEntry>,
+}
+
+impl SessionLog {
+    /// Returns the recorded commands in order.
+    pub fn commands(&self) -> impl Iterator<Item = RecordedCommand> + '_ {
+        self.entries.iter().filter_map(|entry| match entry {
+            LogEntry::Command(command) => Some(*command),
+            _ => None,
+        })
+    }
+
+    /// Compares `self` (the expected session) with `actual` entry by entry.
+    ///
+    /// # Returns
+    ///
+    /// The first `Divergence`, or `None` if both logs are identical.
+    pub fn first_divergence(&self, actual: &SessionLog) -> Option<Divergence> {
+        let len = self.entries.len().max(actual.entries.len());
+        (0..len)
+            .map(|index| Divergence {
+                index,
+                expected: self.entries.get(index).copied(),
+                actual: actual.entries.get(index).copied(),
+            })
+            .find(|divergence| divergence.expected != divergence.actual)
+    }
+
+    /// Returns the index of the first entry that cannot be replayed, if any.
+    fn first_unrecordable(&self) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| *entry == LogEntry::Unrecordable)
+    }
+}
+
+/// First point where a replayed session differs from its log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Divergence {
+    /// Index of the entry in the logs.
+    pub index: usize,
+    /// Entry of the recorded log, or `None` if it ended earlier.
+    pub expected: Option<LogEntry>,
+    /// Entry of the replayed session, or `None` if it ended earlier.
+    pub actual: Option<LogEntry>,
+}
+
+impl Display for Divergence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Replay diverged at entry {}: expected {:?}, found {:?}",
+            self.index, self.expected, self.actual
+        )
+    }
+}
+
+impl Error for Divergence {}
+
+/// Error returned by [`Replayer::replay`] and [`Replayer::verify`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    /// The configuration contains invalid values.
+    Config(ConfigError),
+    /// The log contains a command without a recorded form.
+    Unrecordable {
+        /// Index of the [`LogEntry::Unrecordable`] entry.
+        index: usize,
+    },
+    /// The replayed session differs from the log.
+    Divergence(Box<Divergence>),
+}
+
+impl Display for ReplayError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Config(error) => write!(f, "{error}"),
+            Self::Unrecordable { index } => {
+                write!(f, "Entry {index} is a command that cannot be replayed")
+            }
+            Self::Divergence(divergence) => write!(f, "{divergence}"),
+        }
+    }
+}
+
+impl Error for ReplayError {}
+
+impl From<ConfigError> for ReplayError {
+    fn from(error: ConfigError) -> Self {
+        Self::Config(error)
+    }
+}
+
+/// Records a session into a [`SessionLog`].
+///
+/// [`Recorder::wrap`] wraps a `UserOutput`; the wrapper passes every callback through
+/// and logs the commands received by the game loop, the lottery results and the state
+/// transitions. The game reports every command its input returns to the output, so the
+/// input is passed to the game unchanged. The recorder is a handle sharing the log with
+/// its wrapper, so it can be read after the game ran.
+///
+/// A game that does not start fresh, e.g. one built by a
+/// [`GameBuilder`](crate::game::GameBuilder) or resumed by [`Game::restore`], must
+/// announce its position with [`Recorder::start_from`] before it runs.
+///
+/// Only built-in commands have a recorded form (see [`ControlCommand::recorded`]); any
+/// other command still reaches the game, but is logged as [`LogEntry::Unrecordable`].
+///
+/// [`ControlCommand::recorded`]: crate::command::ControlCommand::recorded
+///
+/// # Examples
+///
+/// ```ignore
+/// let recorder = Recorder::new();
+/// let output = recorder.wrap(output);
+/// let mut game: Game<_, _, _, StdRng> = Game::from_seed(CONFIG_EXAMPLE, input, output, 42)?;
+/// game.run();
+///
+/// let log = recorder.log();
+/// Replayer::verify(&log, CONFIG_EXAMPLE, StdRng::seed_from_u64(42))?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Recorder {
+    log: Rc<RefCell<SessionLog>>,
+}
+
+impl Recorder {
+    /// Creates a recorder with an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wraps an output so that the session it observes is recorded.
+    ///
+    /// # Arguments
+    ///
+    /// * `output` - Output receiving every callback after it is recorded
+    ///
+    /// # Returns
+    ///
+    /// The wrapped output, to be passed to `Game`.
+    pub fn wrap<O>(&self, output: O) -> RecordedOutput<O> {
+        RecordedOutput {
+            output,
+            log: Rc::clone(&self.log),
+        }
+    }
+
+    /// Records the position the game starts from.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - Snapshot of the game before it runs, e.g. `game.snapshot()`
+    pub fn start_from<F: RushContinueFn, R>(&self, snapshot: &GameSnapshot<F, R>) {
+        self.log.borrow_mut().start = Some(StartPosition::of(snapshot));
+    }
+
+    /// Returns a copy of the log recorded so far.
+    pub fn log(&self) -> SessionLog {
+        self.log.borrow().clone()
+    }
+
+    fn push(log: &RefCell<SessionLog>, entry: LogEntry) {
+        log.borrow_mut().entries.push(entry);
+    }
+}
+
+/// Output wrapped by a [`Recorder`].
+pub struct RecordedOutput<O> {
+    output: O,
+    log: Rc<RefCell<SessionLog>>,
+}
+
+impl<O> RecordedOutput<O> {
+    /// Returns a reference to the wrapped output.
+    pub fn inner(&self) -> &O {
+        &self.output
+    }
+}
+
+impl<O: UserOutput> UserOutput for RecordedOutput<O> {
+    fn default(&mut self, state: Transition) {
+        Recorder::push(&self.log, LogEntry::Transition(state));
+        self.output.default(state);
+    }
+
+    fn finish_game(&mut self, state: &GameState) {
+        self.output.finish_game(state);
+    }
+
+    fn lottery_normal(&mut self, result: LotteryResult) {
+        self.output.lottery_normal(result);
+    }
+
+    fn lottery_rush(&mut self, result: LotteryResult) {
+        self.output.lottery_rush(result);
+    }
+
+    fn lottery_rush_continue(&mut self, result: LotteryResult) {
+        self.output.lottery_rush_continue(result);
+    }
+
+    fn lottery_kakuhen(&mut self, result: LotteryResult) {
+        self.output.lottery_kakuhen(result);
+    }
+
+    fn jackpot_category(&mut self, category: JackpotCategory) {
+        self.output.jackpot_category(category);
+    }
+
+    fn probability_error(&mut self, error: ProbabilityError) {
+        self.output.probability_error(error);
+    }
+
+    fn bonus_round(&mut self, bonus: Bonus) {
+        self.output.bonus_round(bonus);
+    }
+
+    fn ceiling(&mut self, ceiling: Ceiling) {
+        self.output.ceiling(ceiling);
+    }
+
+    fn command_rejected(&mut self, error: GameError) {
+        self.output.command_rejected(error);
+    }
+
+    fn command_received(&mut self, command: Option<RecordedCommand>) {
+        let entry = command.map_or(LogEntry::Unrecordable, LogEntry::Command);
+        Recorder::push(&self.log, entry);
+        self.output.command_received(command);
+    }
+
+    fn event(&mut self, event: GameEvent) {
+        if let GameEvent::LotteryDrawn { mode, result } = event {
+            Recorder::push(&self.log, LogEntry::Lottery { mode, result });
+        }
+        self.output.event(event);
+    }
+}
+
+/// Input replaying the commands of a [`SessionLog`].
+///
+/// After the last recorded command the replayer ends the game loop.
+pub struct Replayer {
+    commands: std::vec::IntoIter<RecordedCommand>,
+}
+
+impl Replayer {
+    /// Creates a replayer feeding the commands of `log`.
+    pub fn new(log: &SessionLog) -> Self {
+        Self {
+            commands: log.commands().collect::<Vec<_>>().into_iter(),
+        }
+    }
+
+    /// Replays `log` into `output`.
+    ///
+    /// The game starts from the recorded [`StartPosition`], or fresh if there is none,
+    /// and reports the replayed session to `output` like the recorded game did.
+    ///
+    /// # Arguments
+    ///
+    /// * `log` - The recorded session
+    /// * `config` - Configuration of the recorded game
+    /// * `rng` - Lottery RNG in the state the recorded game started with (e.g. the same seed)
+    /// * `output` - Output receiving the replayed session
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Game)` in the state the replay ended in
+    /// - `Err(ReplayError::Unrecordable)` if the log contains a command that cannot be replayed
+    /// - `Err(ReplayError::Config)` if the configuration contains invalid values, or the
+    ///   starting position cannot occur with it
+    pub fn replay<O, F, R>(
+        log: &SessionLog,
+        config: Config<F>,
+        rng: R,
+        output: O,
+    ) -> Result<Game<Self, O, F, R>, ReplayError>
+    where
+        O: UserOutput,
+        F: RushContinueFn,
+        R: LotterySource,
+    {
+        if let Some(index) = log.first_unrecordable() {
+            return Err(ReplayError::Unrecordable { index });
+        }
+
+        let input = Self::new(log);
+        let mut game = match log.start {
+            None => Game::with_rng(config, input, output, rng)?,
+            Some(start) => Game::restore(start.snapshot(config, rng), input, output)?,
+        };
+        game.run();
+        Ok(game)
+    }
+
+    /// Replays `log` and checks that it reproduces every recorded command, lottery
+    /// outcome and transition.
+    ///
+    /// # Arguments
+    ///
+    /// * `log` - The recorded session
+    /// * `config` - Configuration of the recorded game
+    /// * `rng` - Lottery RNG in the state the recorded game started with (e.g. the same seed)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if the replay reproduces the log exactly
+    /// - `Err(ReplayError::Divergence)` with the first entry that differs
+    /// - `Err(ReplayError::Unrecordable)` if the log contains a command that cannot be replayed
+    /// - `Err(ReplayError::Config)` if the configuration contains invalid values
+    pub fn verify<F, R>(log: &SessionLog, config: Config<F>, rng: R) -> Result<(), ReplayError>
+    where
+        F: RushContinueFn,
+        R: LotterySource,
+    {
+        let recorder = Recorder::new();
+        Self::replay(log, config, rng, recorder.wrap(NoOutput))?;
+
+        let mut replayed = recorder.log();
+        // A log recorded without ending the game loop is followed by the replayer's `Quit`
+        // and the transition reported with it
+        let quit = LogEntry::Command(RecordedCommand::Quit);
+        if !log.entries.contains(&quit) && replayed.entries.get(log.entries.len()) == Some(&quit) {
+            replayed.entries.truncate(log.entries.len());
+        }
+
+        match log.first_divergence(&replayed) {
+            None => Ok(()),
+            Some(divergence) => Err(ReplayError::Divergence(Box::new(divergence))),
+        }
+    }
+}
+
+impl<O, F, R> UserInput<O, F, R> for Replayer
+where
+    O: UserOutput,
+    F: RushContinueFn,
+    R: LotterySource,
+{
+    fn wait_for_input(&mut self) -> Command<Self, O, F, R> {
+        self.commands
+            .next()
+            .map_or(Command::FinishGame, RecordedCommand::into_command)
+    }
+}
+
+/// Output of a verifying replay; only the recorder looks at the session.
+struct NoOutput;
+
+impl UserOutput for NoOutput {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush_continue(&mut self, _result: LotteryResult) {}
+}
@@ -0,0 +1,56 @@
+use rand::{SeedableRng, rngs::StdRng};
+
+/// Independent random number generators for every random component of a session,
+/// derived from a single master seed.
+///
+/// Each generator is drawn from a `StdRng` seeded with the master seed, so the
+/// components get uncorrelated streams while the whole session stays reproducible.
+/// Seeding every component with the same value instead (e.g. through the separate
+/// `from_seed` constructors) gives streams that start alike.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{
+///     START_HOLE_PROBABILITY_EXAMPLE, command::LaunchBallFlowProducer, seed::SessionRngs,
+///     slot::SlotProducer,
+/// };
+///
+/// let rngs = SessionRngs::from_seed(42);
+/// let mut producer = LaunchBallFlowProducer::with_rng(START_HOLE_PROBABILITY_EXAMPLE, rngs.start_hole);
+/// let mut slot_producer = SlotProducer::with_rng(3, vec![1, 2, 3], rngs.slot);
+/// // Pass `rngs.lottery` to `Game::with_rng`
+///
+/// let again = SessionRngs::from_seed(42);
+/// assert_eq!(rngs.lottery, again.lottery);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionRngs {
+    /// RNG deciding whether launched balls enter the start hole
+    /// (for [`LaunchBallFlowProducer`](crate::command::LaunchBallFlowProducer)).
+    pub start_hole: StdRng,
+    /// RNG drawing the lotteries (for [`Game::with_rng`](crate::Game::with_rng)).
+    pub lottery: StdRng,
+    /// RNG choosing the slot presentation (for [`SlotProducer`](crate::slot::SlotProducer)).
+    pub slot: StdRng,
+}
+
+impl SessionRngs {
+    /// Derives the generators of a session from `seed`.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Master seed of the session
+    ///
+    /// # Returns
+    ///
+    /// The generators, identical for identical seeds.
+    pub fn from_seed(seed: u64) -> Self {
+        let mut seeder = StdRng::seed_from_u64(seed);
+        Self {
+            start_hole: StdRng::from_rng(&mut seeder),
+            lottery: StdRng::from_rng(&mut seeder),
+            slot: StdRng::from_rng(&mut seeder),
+        }
+    }
+}
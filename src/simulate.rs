@@ -0,0 +1,340 @@
+use std::{fmt::Display, thread};
+
+use rand::rngs::StdRng;
+
+use crate::{
+    command::{Command, LaunchBallFlowProducer},
+    config::{Config, ConfigError, RushContinueFn},
+    event::GameEvent,
+    game::{Game, GameState, Transition},
+    interface::{UserInput, UserOutput},
+    lottery::LotteryResult,
+    odds::Odds,
+    seed::SessionRngs,
+};
+
+/// Settings of a Monte Carlo batch simulation.
+///
+/// Every session starts a game with `Config::balls::init_balls` balls and launches
+/// up to `launches` balls, stopping early when the balls run out. Sessions are
+/// independent and run in parallel on all available cores.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::{CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE, simulate::Simulation};
+///
+/// let simulation = Simulation {
+///     start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+///     launches: 10_000,
+///     sessions: 4,
+///     seed: Some(42),
+/// };
+/// let report = simulation.run(&CONFIG_EXAMPLE).unwrap();
+///
+/// assert_eq!(report.final_balls.len(), 4);
+/// assert_eq!(report, simulation.run(&CONFIG_EXAMPLE).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Simulation {
+    /// Probability (0.0 to 1.0) that a launched ball enters the start hole.
+    pub start_hole_probability: f64,
+    /// Maximum number of ball launches per session.
+    pub launches: usize,
+    /// Number of sessions to simulate.
+    pub sessions: usize,
+    /// Seed making the report reproducible, or `None` for fresh randomness.
+    ///
+    /// With a seed the report does not depend on the number of threads.
+    pub seed: Option<u64>,
+}
+
+/// Number of lottery draws and wins of one kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct HitRate {
+    /// Number of lottery draws.
+    pub spins: usize,
+    /// Number of winning draws.
+    pub hits: usize,
+}
+
+impl HitRate {
+    /// Returns the share of winning draws, or 0.0 if nothing was drawn.
+    pub fn rate(&self) -> f64 {
+        if self.spins == 0 {
+            0.0
+        } else {
+            self.hits as f64 / self.spins as f64
+        }
+    }
+
+    /// Returns the share of winning draws in odds notation.
+    pub fn odds(&self) -> Odds {
+        Odds(self.rate())
+    }
+
+    fn record(&mut self, result: LotteryResult) {
+        self.spins += 1;
+        if result.is_win() {
+            self.hits += 1;
+        }
+    }
+
+    fn add(&mut self, other: &HitRate) {
+        self.spins += other.spins;
+        self.hits += other.hits;
+    }
+}
+
+impl Display for HitRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{} hits, {}", self.hits, self.spins, self.odds())
+    }
+}
+
+/// Aggregated statistics of a batch simulation.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SimulationReport {
+    /// Number of simulated sessions.
+    pub sessions: usize,
+    /// Total number of balls launched, including free rush and ST launches.
+    pub launches: usize,
+    /// Number of launches paid from the player's own balls.
+    ///
+    /// These are the launches in Normal, Kakuhen and Jitan mode and the balls fed
+    /// into the attacker during a bonus, whose payout already deducts them. Rush
+    /// balls and launches during an ST rush cost the player nothing.
+    pub paid_launches: usize,
+    /// Total number of balls returned to the player (paid launches plus net gain).
+    pub balls_out: usize,
+    /// Draws with normal odds (Normal and Jitan mode).
+    pub normal: HitRate,
+    /// Draws with rush odds (Rush and SpecialTimes mode).
+    pub rush: HitRate,
+    /// Rush continuation draws.
+    pub rush_continue: HitRate,
+    /// Draws with kakuhen odds.
+    pub kakuhen: HitRate,
+    /// Number of rushes by length: `rush_lengths[i]` rushes lasted `i + 1` rounds.
+    ///
+    /// A rush still running when its session ends is counted with the rounds it reached.
+    pub rush_lengths: Vec<usize>,
+    /// Longest rush chain observed.
+    pub max_chain: usize,
+    /// Balls left at the end of each session, in session order (0 when the balls ran out).
+    pub final_balls: Vec<usize>,
+}
+
+impl SimulationReport {
+    /// Returns the payout ratio: balls returned per ball paid in (`paid_launches`).
+    ///
+    /// A value above 1.0 means the player gained balls on average.
+    pub fn payout_ratio(&self) -> f64 {
+        if self.paid_launches == 0 {
+            0.0
+        } else {
+            self.balls_out as f64 / self.paid_launches as f64
+        }
+    }
+
+    /// Returns the number of rushes, including those still running at the end of a session.
+    pub fn rush_count(&self) -> usize {
+        self.rush_lengths.iter().sum()
+    }
+
+    /// Returns the average number of rounds of a rush, or 0.0 without rushes.
+    pub fn average_rush_length(&self) -> f64 {
+        let count = self.rush_count();
+        if count == 0 {
+            return 0.0;
+        }
+        let sum: usize = self
+            .rush_lengths
+            .iter()
+            .enumerate()
+            .map(|(i, count)| (i + 1) * count)
+            .sum();
+        sum as f64 / count as f64
+    }
+
+    /// Returns the average number of balls left at the end of a session.
+    pub fn average_final_balls(&self) -> f64 {
+        if self.final_balls.is_empty() {
+            0.0
+        } else {
+            self.final_balls.iter().sum::<usize>() as f64 / self.final_balls.len() as f64
+        }
+    }
+
+    /// Returns the number of sessions that ran out of balls.
+    pub fn busted(&self) -> usize {
+        self.final_balls.iter().filter(|&&balls| balls == 0).count()
+    }
+
+    fn record_rush(&mut self, n: usize) {
+        if self.rush_lengths.len() < n {
+            self.rush_lengths.resize(n, 0);
+        }
+        self.rush_lengths[n - 1] += 1;
+        self.max_chain = self.max_chain.max(n);
+    }
+
+    fn add(&mut self, other: &SimulationReport) {
+        self.sessions += other.sessions;
+        self.launches += other.launches;
+        self.paid_launches += other.paid_launches;
+        self.balls_out += other.balls_out;
+        self.normal.add(&other.normal);
+        self.rush.add(&other.rush);
+        self.rush_continue.add(&other.rush_continue);
+        self.kakuhen.add(&other.kakuhen);
+        if self.rush_lengths.len() < other.rush_lengths.len() {
+            self.rush_lengths.resize(other.rush_lengths.len(), 0);
+        }
+        for (total, count) in self.rush_lengths.iter_mut().zip(&other.rush_lengths) {
+            *total += count;
+        }
+        self.max_chain = self.max_chain.max(other.max_chain);
+        self.final_balls.extend_from_slice(&other.final_balls);
+    }
+}
+
+impl Simulation {
+    /// Runs the simulation for the given configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Machine configuration shared by every session
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(SimulationReport)` with the statistics of all sessions
+    /// - `Err(ConfigError)` if the configuration contains invalid values
+    pub fn run<F>(&self, config: &Config<F>) -> Result<SimulationReport, ConfigError>
+    where
+        F: RushContinueFn + Clone + Sync,
+    {
+        config.clone().validate()?;
+
+        let seed = self.seed.unwrap_or_else(rand::random);
+        let threads = thread::available_parallelism()
+            .map_or(1, |n| n.get())
+            .min(self.sessions)
+            .max(1);
+
+        let mut sessions: Vec<(usize, SimulationReport)> = thread::scope(|s| {
+            let handles: Vec<_> = (0..threads)
+                .map(|t| {
+                    s.spawn(move || {
+                        (t..self.sessions)
+                            .step_by(threads)
+                            .map(|i| (i, self.run_session(config, seed.wrapping_add(i as u64))))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        sessions.sort_by_key(|(i, _)| *i);
+
+        let mut report = SimulationReport::default();
+        for (_, session) in &sessions {
+            report.add(session);
+        }
+        Ok(report)
+    }
+
+    /// Plays a single session whose every random decision derives from `seed`.
+    fn run_session<F>(&self, config: &Config<F>, seed: u64) -> SimulationReport
+    where
+        F: RushContinueFn + Clone,
+    {
+        let rngs = SessionRngs::from_seed(seed);
+        let mut producer =
+            LaunchBallFlowProducer::with_rng(self.start_hole_probability, rngs.start_hole);
+        let output = SessionOutput {
+            report: SimulationReport {
+                sessions: 1,
+                ..SimulationReport::default()
+            },
+        };
+        let mut game = Game::with_rng(config.clone(), NoInput, output, rngs.lottery)
+            .expect("configuration was validated");
+
+        let init_balls = config.balls.init_balls;
+        let _ = game.start();
+        let mut launches = 0;
+        let mut paid_launches = 0;
+        while launches < self.launches && !game.state().is_uninitialized() {
+            let paid = game.bonus().is_some()
+                || matches!(
+                    game.state(),
+                    GameState::Normal { .. } | GameState::Kakuhen { .. } | GameState::Jitan { .. }
+                );
+            let _ = game.run_step_with_command(Command::control(producer.produce()));
+            launches += 1;
+            paid_launches += usize::from(paid);
+        }
+
+        // Reports the transition caused by the last launch
+        let _ = game.run_step_with_command(Command::FinishGame);
+        let final_balls = game.state().balls();
+
+        let mut report = game.output().report.clone();
+        // A rush cut off by the end of the session counts with the rounds it reached
+        if let GameState::Rush { n, .. } | GameState::SpecialTimes { n, .. } = *game.state() {
+            report.record_rush(n);
+        }
+        report.launches = launches;
+        report.paid_launches = paid_launches;
+        report.balls_out = (paid_launches + final_balls).saturating_sub(init_balls);
+        report.final_balls.push(final_balls);
+        report
+    }
+}
+
+/// Input of a simulated session; commands are fed by [`Simulation`] directly.
+struct NoInput;
+
+impl<F: RushContinueFn> UserInput<SessionOutput, F, StdRng> for NoInput {
+    fn wait_for_input(&mut self) -> Command<Self, SessionOutput, F, StdRng> {
+        Command::FinishGame
+    }
+}
+
+/// Output collecting the statistics of a single session.
+struct SessionOutput {
+    report: SimulationReport,
+}
+
+impl UserOutput for SessionOutput {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, result: LotteryResult) {
+        self.report.normal.record(result);
+    }
+
+    fn lottery_rush(&mut self, result: LotteryResult) {
+        self.report.rush.record(result);
+    }
+
+    fn lottery_rush_continue(&mut self, result: LotteryResult) {
+        self.report.rush_continue.record(result);
+    }
+
+    fn lottery_kakuhen(&mut self, result: LotteryResult) {
+        self.report.kakuhen.record(result);
+    }
+
+    fn event(&mut self, event: GameEvent) {
+        if let GameEvent::RushEnded { n, .. } = event {
+            self.report.record_rush(n);
+        }
+    }
+}
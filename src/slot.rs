@@ -1,10 +1,11 @@
 use rand::{
-    Rng,
+    Rng, SeedableRng,
+    distr::{Distribution, weighted::WeightedIndex},
     rngs::ThreadRng,
     seq::{IndexedRandom, SliceRandom},
 };
 
-use crate::lottery::{Lose, LotteryResult, Win};
+use crate::lottery::{JackpotCategory, Lose, LotteryResult, Win};
 
 /// Produces visual slot machine sequences based on lottery outcomes.
 ///
@@ -35,6 +36,163 @@ pub struct SlotProducer<T, R: Rng = ThreadRng> {
     choices: Vec<T>,
     /// Random number generator for symbol selection.
     rng: R,
+    /// Reach presentation used by `produce_outcome`, if enabled.
+    reach: Option<ReachConfig>,
+    /// Weights of the symbols on the reels (uniform if `None`).
+    reel_weights: Option<WeightedIndex<u32>>,
+    /// Weights of the winning symbol of `Win::Default` results (reel weights if `None`).
+    win_weights: Option<WeightedIndex<u32>>,
+    /// Weights of the winning symbol of `Win::FakeWin` results (win weights if `None`).
+    fake_win_weights: Option<WeightedIndex<u32>>,
+    /// Jackpot category of each symbol, used by `produce_jackpot` (unrestricted if `None`).
+    categories: Option<Vec<JackpotCategory>>,
+    /// Presentation stages used by `produce_frames`, in order (none if empty).
+    effects: Vec<EffectStage>,
+}
+
+/// Kind of a reach (リーチ) presentation, in increasing order of expectation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ReachKind {
+    /// Normal reach (ノーマルリーチ), frequent and rarely a win.
+    Normal,
+    /// Super reach (スーパーリーチ), a longer presentation with better odds.
+    Super,
+    /// Premium reach (プレミアムリーチ), rare and almost always a win.
+    Premium,
+}
+
+/// Weights of the reach kinds, each kind being chosen in proportion to its weight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ReachWeights {
+    /// Weight of `ReachKind::Normal`.
+    pub normal: u32,
+    /// Weight of `ReachKind::Super`.
+    #[cfg_attr(feature = "serde", serde(rename = "super"))]
+    pub super_: u32,
+    /// Weight of `ReachKind::Premium`.
+    pub premium: u32,
+}
+
+impl ReachWeights {
+    pub(crate) fn total(&self) -> u64 {
+        u64::from(self.normal) + u64::from(self.super_) + u64::from(self.premium)
+    }
+}
+
+/// How often lottery results are presented with a reach.
+///
+/// Real machines signal expectation through reaches: most reaches lose, but wins are
+/// far more likely to show one, and the rarer kinds are reserved for wins.
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::slot::{ReachConfig, ReachWeights};
+///
+/// let reach = ReachConfig {
+///     win: 0.95,
+///     lose: 0.1,
+///     win_kinds: ReachWeights { normal: 20, super_: 70, premium: 10 },
+///     lose_kinds: ReachWeights { normal: 85, super_: 15, premium: 0 },
+/// };
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct ReachConfig {
+    /// Probability (0.0 to 1.0) that a winning result is presented with a reach.
+    pub win: f64,
+    /// Probability (0.0 to 1.0) that a losing result is presented with a reach (ガセリーチ).
+    pub lose: f64,
+    /// Kinds of the reaches on winning results.
+    pub win_kinds: ReachWeights,
+    /// Kinds of the reaches on losing results.
+    pub lose_kinds: ReachWeights,
+}
+
+/// Reel presentation of a lottery result, produced by [`SlotProducer::produce_outcome`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SlotOutcome<T> {
+    /// Symbols shown when the reels stop.
+    ///
+    /// During a reach every reel but the last one shows the same symbol; the last
+    /// reel decides the reach.
+    pub reels: Vec<T>,
+    /// Reach shown before the last reel stops, if any.
+    pub reach: Option<ReachKind>,
+    /// Symbols revealed afterwards for a fake result (revival or slip), if any.
+    pub revival: Option<Vec<T>>,
+}
+
+/// Kind of a presentation stage of [`SlotProducer::produce_frames`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum EffectKind {
+    /// Reach (リーチ): every reel but the last one shows the same symbol.
+    ///
+    /// A reach that does not win stops with the last reel on another symbol.
+    Reach,
+    /// Super reach (スーパーリーチ), a longer development of the reach.
+    ///
+    /// Its frames show the same reels as the reach.
+    SuperReach,
+    /// Reversal (逆転), a last chance after the reach seems to miss.
+    ///
+    /// The last reel is turned onto the reach symbol; a reversal that does not win
+    /// is overturned again as the reel before it slips off.
+    Reversal,
+    /// Revival (復活), a comeback after the reels have stopped on a miss.
+    ///
+    /// Every reel spins again; a revival that does not win stops on a new losing
+    /// sequence.
+    Revival,
+}
+
+/// A presentation stage with its probabilities of being shown.
+///
+/// Stages are passed in order: a stage is only shown if the previous one was, with
+/// the probability given the true outcome. Giving wins higher probabilities than
+/// losses makes the later stages more reliable (e.g. "a revival is 60% hot").
+///
+/// # Examples
+///
+/// ```
+/// use pachislo::slot::{EffectKind, EffectStage};
+///
+/// let stages = vec![
+///     EffectStage { kind: EffectKind::Reach, win: 0.95, lose: 0.1 },
+///     EffectStage { kind: EffectKind::SuperReach, win: 0.7, lose: 0.2 },
+///     EffectStage { kind: EffectKind::Revival, win: 0.3, lose: 0.05 },
+/// ];
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct EffectStage {
+    /// Kind of the stage.
+    pub kind: EffectKind,
+    /// Probability (0.0 to 1.0) that a winning result reaching this stage shows it.
+    pub win: f64,
+    /// Probability (0.0 to 1.0) that a losing result reaching this stage shows it.
+    pub lose: f64,
+}
+
+/// A frame of the presentation produced by [`SlotProducer::produce_frames`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(deny_unknown_fields))]
+pub struct SlotFrame<T> {
+    /// Stage shown in this frame, `None` for reels stopping without a presentation.
+    pub stage: Option<EffectKind>,
+    /// Symbols shown at the end of the frame.
+    pub reels: Vec<T>,
 }
 
 impl<T, R: Rng + Default> SlotProducer<T, R> {
@@ -63,16 +221,57 @@ impl<T, R: Rng + Default> SlotProducer<T, R> {
     /// let producer = SlotProducer::new(3, symbols);
     /// ```
     pub fn new(length: usize, choices: Vec<T>) -> Self {
-        assert!(choices.len() > 1, "Choices must have at least two elements");
-        Self {
-            length,
-            choices,
-            rng: R::default(),
-        }
+        Self::with_rng(length, choices, R::default())
     }
 }
 
-impl<T: Clone, R: Rng> SlotProducer<T, R> {
+impl<T: Clone, R: Rng + Default> SlotProducer<T, R> {
+    /// Creates a new SlotProducer whose reels show each symbol in proportion to its weight.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Number of symbols in each generated sequence
+    /// * `choices` - Available symbols with their weights (at least 2 of positive weight)
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than 2 symbols have a positive weight.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pachislo::slot::SlotProducer;
+    ///
+    /// // The premium 7 is rare on the reels
+    /// let producer: SlotProducer<u8> = SlotProducer::weighted(3, vec![(1, 10), (3, 10), (7, 1)]);
+    /// ```
+    pub fn weighted(length: usize, choices: Vec<(T, u32)>) -> Self {
+        let (choices, weights) = choices.into_iter().unzip();
+        Self::new(length, choices).with_weights(weights)
+    }
+}
+
+impl<T, R: Rng + SeedableRng> SlotProducer<T, R> {
+    /// Creates a new SlotProducer whose random number generator is seeded with `seed`.
+    ///
+    /// Producers created with the same seed generate identical symbol sequences
+    /// for identical lottery results.
+    ///
+    /// # Arguments
+    ///
+    /// * `length` - Number of symbols in each generated sequence
+    /// * `choices` - Vector of available symbols (must have at least 2 elements)
+    /// * `seed` - Seed for the symbol selection random number generator
+    ///
+    /// # Panics
+    ///
+    /// Panics if `choices` has fewer than 2 elements.
+    pub fn from_seed(length: usize, choices: Vec<T>, seed: u64) -> Self {
+        Self::with_rng(length, choices, R::seed_from_u64(seed))
+    }
+}
+
+impl<T, R: Rng> SlotProducer<T, R> {
     /// Creates a new SlotProducer with a custom random number generator.
     ///
     /// This constructor is useful for testing or when deterministic behavior
@@ -81,25 +280,206 @@ impl<T: Clone, R: Rng> SlotProducer<T, R> {
     /// # Arguments
     ///
     /// * `length` - Number of symbols in each generated sequence
-    /// * `choices` - Vector of available symbols
+    /// * `choices` - Vector of available symbols (must have at least 2 elements)
     /// * `rng` - Custom random number generator implementing `Rng`
     ///
     /// # Returns
     ///
     /// A new `SlotProducer` instance using the provided RNG.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `choices` has fewer than 2 elements.
     pub fn with_rng(length: usize, choices: Vec<T>, rng: R) -> Self {
+        assert!(choices.len() > 1, "Choices must have at least two elements");
         Self {
             length,
             choices,
             rng,
+            reach: None,
+            reel_weights: None,
+            win_weights: None,
+            fake_win_weights: None,
+            categories: None,
+            effects: Vec::new(),
         }
     }
+}
+
+impl<T: Clone, R: Rng> SlotProducer<T, R> {
+    /// Sets the weights of the symbols on the reels.
+    ///
+    /// Each symbol of `choices` appears in proportion to its weight, in losing
+    /// sequences as well as on wins without weights of their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `weights` - Weight of each symbol, in the order of `choices`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `choices` differ in length, or if fewer than 2 symbols
+    /// have a positive weight, as this would make losing sequences impossible to generate.
+    pub fn with_weights(mut self, weights: Vec<u32>) -> Self {
+        assert!(
+            weights.iter().filter(|&&weight| weight > 0).count() > 1,
+            "Weights must be positive for at least two symbols"
+        );
+        self.reel_weights = Some(self.weighted_index(weights));
+        self
+    }
+
+    /// Sets the weights of the winning symbol of `Win::Default` or `Win::FakeWin` results.
+    ///
+    /// Machines publish how often each symbol wins, e.g. odd (kakuhen) numbers or a
+    /// premium 7 being more likely on some results. Fake wins use the weights of
+    /// `Win::Default` unless they have their own.
+    ///
+    /// # Arguments
+    ///
+    /// * `win` - The kind of winning result
+    /// * `weights` - Weight of each symbol, in the order of `choices`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `weights` and `choices` differ in length, or if all weights are 0.
+    pub fn with_win_weights(mut self, win: Win, weights: Vec<u32>) -> Self {
+        let weights = Some(self.weighted_index(weights));
+        match win {
+            Win::Default => self.win_weights = weights,
+            Win::FakeWin => self.fake_win_weights = weights,
+        }
+        self
+    }
+
+    /// Maps every symbol to the jackpot category it announces.
+    ///
+    /// Many machines tell a rush from a normal bonus by the winning symbol, e.g. odd
+    /// numbers for a rush and even numbers for a normal bonus.
+    /// [`SlotProducer::produce_jackpot`] then only lets symbols of the jackpot's category
+    /// win, keeping their weights relative to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `categories` - Category of each symbol, in the order of `choices`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `categories` and `choices` differ in length.
+    pub fn with_categories(mut self, categories: Vec<JackpotCategory>) -> Self {
+        assert_eq!(
+            categories.len(),
+            self.choices.len(),
+            "Categories must be given for every symbol"
+        );
+        self.categories = Some(categories);
+        self
+    }
+
+    fn weighted_index(&self, weights: Vec<u32>) -> WeightedIndex<u32> {
+        assert_eq!(
+            weights.len(),
+            self.choices.len(),
+            "Weights must be given for every symbol"
+        );
+        WeightedIndex::new(weights).expect("Weights must be positive for at least one symbol")
+    }
+
+    /// Chooses a symbol index, for the winning symbol of `win` or for any reel.
+    fn pick(&mut self, win: Option<Win>) -> usize {
+        let weights = match win {
+            Some(Win::Default) => self.win_weights.as_ref(),
+            Some(Win::FakeWin) => self.fake_win_weights.as_ref().or(self.win_weights.as_ref()),
+            None => None,
+        }
+        .or(self.reel_weights.as_ref());
+
+        match weights {
+            Some(weights) => weights.sample(&mut self.rng),
+            None => self.rng.random_range(0..self.choices.len()),
+        }
+    }
+
+    /// Chooses the winning symbol of `win` among the symbols of `category`.
+    ///
+    /// Without categories, any symbol can win.
+    fn pick_jackpot(&mut self, win: Win, category: Option<JackpotCategory>) -> usize {
+        let (Some(category), Some(categories)) = (category, &self.categories) else {
+            return self.pick(Some(win));
+        };
+
+        let weights: Vec<u32> = match win {
+            Win::Default => self.win_weights.as_ref(),
+            Win::FakeWin => self.fake_win_weights.as_ref().or(self.win_weights.as_ref()),
+        }
+        .or(self.reel_weights.as_ref())
+        .map_or_else(
+            || vec![1; self.choices.len()],
+            |weights| weights.weights().collect(),
+        );
+        let weights = weights
+            .into_iter()
+            .zip(categories)
+            .map(|(weight, &symbol)| if symbol == category { weight } else { 0 });
+
+        WeightedIndex::new(weights)
+            .unwrap_or_else(|_| panic!("No symbol can win for the jackpot category {category:?}"))
+            .sample(&mut self.rng)
+    }
+
+    /// Enables reach presentation in [`SlotProducer::produce_outcome`].
+    ///
+    /// # Arguments
+    ///
+    /// * `reach` - How often and which reaches are shown on wins and losses
+    ///
+    /// # Panics
+    ///
+    /// Panics if a probability of `reach` is outside 0.0 to 1.0, or if reaches can
+    /// happen while all weights of their kinds are 0.
+    pub fn with_reach(mut self, reach: ReachConfig) -> Self {
+        for (probability, kinds) in [(reach.win, reach.win_kinds), (reach.lose, reach.lose_kinds)] {
+            assert!(
+                (0.0..=1.0).contains(&probability),
+                "Reach probabilities must be between 0.0 and 1.0"
+            );
+            assert!(
+                probability == 0.0 || kinds.total() > 0,
+                "Reach kinds must have a positive weight"
+            );
+        }
+        self.reach = Some(reach);
+        self
+    }
+
+    /// Sets the presentation stages of [`SlotProducer::produce_frames`].
+    ///
+    /// Stages are drawn the same way for default and fake results; a fake result adds
+    /// its own frame after them (see [`SlotProducer::produce_frames`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `stages` - Stages in the order they are passed
+    ///
+    /// # Panics
+    ///
+    /// Panics if a probability of `stages` is outside 0.0 to 1.0.
+    pub fn with_effects(mut self, stages: Vec<EffectStage>) -> Self {
+        for stage in &stages {
+            assert!(
+                (0.0..=1.0).contains(&stage.win) && (0.0..=1.0).contains(&stage.lose),
+                "Effect probabilities must be between 0.0 and 1.0"
+            );
+        }
+        self.effects = stages;
+        self
+    }
 
     /// Generates a winning slot machine sequence.
     ///
     /// Creates a sequence where all symbols are identical, representing
     /// a visual win condition. The specific symbol is chosen randomly
-    /// from the available choices.
+    /// from the available choices, by the reel weights if set.
     ///
     /// # Returns
     ///
@@ -117,8 +497,14 @@ impl<T: Clone, R: Rng> SlotProducer<T, R> {
     /// assert!(winning.windows(2).all(|w| w[0] == w[1])); // All symbols identical
     /// ```
     pub fn produce_win(&mut self) -> Vec<T> {
-        let choice = self.choices.choose(&mut self.rng).unwrap();
-        vec![choice.clone(); self.length]
+        let symbol = self.pick(None);
+        vec![self.choices[symbol].clone(); self.length]
+    }
+
+    /// Generates a winning sequence of the symbol chosen for `win` and `category`.
+    fn produce_win_of(&mut self, win: Win, category: Option<JackpotCategory>) -> Vec<T> {
+        let symbol = self.pick_jackpot(win, category);
+        vec![self.choices[symbol].clone(); self.length]
     }
 
     /// Generates a losing slot machine sequence.
@@ -153,7 +539,29 @@ impl<T: Clone, R: Rng> SlotProducer<T, R> {
     /// 3. Distribute the sequence length between the two groups
     /// 4. Fill positions with symbols from respective groups
     /// 5. Shuffle the final sequence to randomize positions
+    ///
+    /// With reel weights, every position is drawn by the weights instead, until at
+    /// least two different symbols appear.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the producer generates sequences of fewer than 2 symbols, as they
+    /// cannot show two different symbols.
     pub fn produce_lose(&mut self) -> Vec<T> {
+        assert!(self.length > 1, "Losing sequences need at least two reels");
+
+        if self.reel_weights.is_some() {
+            loop {
+                let symbols: Vec<usize> = (0..self.length).map(|_| self.pick(None)).collect();
+                if symbols.iter().any(|&symbol| symbol != symbols[0]) {
+                    return symbols
+                        .into_iter()
+                        .map(|symbol| self.choices[symbol].clone())
+                        .collect();
+                }
+            }
+        }
+
         // Vector with reference choices
         let mut ref_choices: Vec<&T> = self.choices.iter().collect();
         ref_choices.shuffle(&mut self.rng);
@@ -228,11 +636,395 @@ impl<T: Clone, R: Rng> SlotProducer<T, R> {
     /// assert!(second.is_some());
     /// ```
     pub fn produce(&mut self, result: &LotteryResult) -> (Vec<T>, Option<Vec<T>>) {
+        self.produce_sequences(result, None)
+    }
+
+    /// Generates the sequences of `result`, its winning symbol of `category` if given.
+    fn produce_sequences(
+        &mut self,
+        result: &LotteryResult,
+        category: Option<JackpotCategory>,
+    ) -> (Vec<T>, Option<Vec<T>>) {
         match result {
-            LotteryResult::Win(Win::Default) => (self.produce_win(), None),
-            LotteryResult::Win(Win::FakeWin) => (self.produce_lose(), Some(self.produce_win())),
+            LotteryResult::Win(Win::Default) => (self.produce_win_of(Win::Default, category), None),
+            LotteryResult::Win(Win::FakeWin) => (
+                self.produce_lose(),
+                Some(self.produce_win_of(Win::FakeWin, category)),
+            ),
             LotteryResult::Lose(Lose::Default) => (self.produce_lose(), None),
             LotteryResult::Lose(Lose::FakeLose) => (self.produce_win(), Some(self.produce_lose())),
         }
     }
+
+    /// Generates the reel presentation of a lottery result, with reaches if enabled.
+    ///
+    /// Without [`SlotProducer::with_reach`] the reels are those of [`SlotProducer::produce`]
+    /// and no reach is shown. With it, a reach is drawn with the configured probability
+    /// and kind for winning or losing results, and:
+    ///
+    /// - `Win::Default`: The reach resolves into a winning sequence
+    /// - `Win::FakeWin`: The reach misses by the last reel, then revives into a win of
+    ///   the same symbol (without a reach, a losing sequence revives)
+    /// - `Lose::Default`: The reach misses by the last reel (without a reach, a losing sequence)
+    /// - `Lose::FakeLose`: A winning sequence whose last reel slips off to another symbol,
+    ///   chosen like the last reel of a missed reach
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The lottery result determining what sequences to generate
+    ///
+    /// # Returns
+    ///
+    /// A `SlotOutcome` describing the reels, the reach and the revealed sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the producer generates sequences of fewer than 2 symbols and a reach
+    /// is drawn or the result is not `Win::Default`.
+    pub fn produce_outcome(&mut self, result: &LotteryResult) -> SlotOutcome<T> {
+        self.produce_outcome_of(result, None)
+    }
+
+    /// Generates the reel presentation of a jackpot of `category`.
+    ///
+    /// Like [`SlotProducer::produce_outcome`], but a winning result only shows symbols
+    /// mapped to `category` by [`SlotProducer::with_categories`], so the reels announce
+    /// where the jackpot leads. The category is reported by
+    /// [`UserOutput::jackpot_category`](crate::interface::UserOutput::jackpot_category)
+    /// right before the winning result. Losing results are produced as usual.
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The lottery result determining what sequences to generate
+    /// * `category` - The category of the jackpot won by `result`
+    ///
+    /// # Returns
+    ///
+    /// A `SlotOutcome` describing the reels, the reach and the revealed sequence.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no symbol of `category` can win `result`, or if the producer generates
+    /// sequences of fewer than 2 symbols and a reach is drawn or the result is not
+    /// `Win::Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pachislo::{
+    ///     lottery::{JackpotCategory, LotteryResult, Win},
+    ///     slot::SlotProducer,
+    /// };
+    /// use rand::rngs::StdRng;
+    ///
+    /// // Odd numbers lead into a rush, even numbers are normal bonuses
+    /// let mut producer = SlotProducer::<_, StdRng>::from_seed(3, vec![1, 2, 3, 4], 7)
+    ///     .with_categories(vec![
+    ///         JackpotCategory::Rush,
+    ///         JackpotCategory::Normal,
+    ///         JackpotCategory::Rush,
+    ///         JackpotCategory::Normal,
+    ///     ]);
+    ///
+    /// let result = LotteryResult::Win(Win::Default);
+    /// let outcome = producer.produce_jackpot(&result, JackpotCategory::Rush);
+    /// assert!(outcome.reels.iter().all(|&symbol| symbol == outcome.reels[0]));
+    /// assert_eq!(outcome.reels[0] % 2, 1);
+    /// ```
+    pub fn produce_jackpot(
+        &mut self,
+        result: &LotteryResult,
+        category: JackpotCategory,
+    ) -> SlotOutcome<T> {
+        self.produce_outcome_of(result, Some(category))
+    }
+
+    /// Generates the reel presentation of `result`, its winning symbol of `category` if given.
+    fn produce_outcome_of(
+        &mut self,
+        result: &LotteryResult,
+        category: Option<JackpotCategory>,
+    ) -> SlotOutcome<T> {
+        let Some(config) = self.reach else {
+            let (reels, revival) = self.produce_sequences(result, category);
+            return SlotOutcome {
+                reels,
+                reach: None,
+                revival,
+            };
+        };
+
+        let reach = if result.is_win() {
+            self.draw_reach(config.win, config.win_kinds)
+        } else {
+            self.draw_reach(config.lose, config.lose_kinds)
+        };
+        let symbol = match result {
+            LotteryResult::Win(win) => self.pick_jackpot(*win, category),
+            LotteryResult::Lose(_) => self.pick(None),
+        };
+
+        let (reels, revival) = match result {
+            LotteryResult::Win(Win::Default) => (self.symbols(symbol, None), None),
+            LotteryResult::Win(Win::FakeWin) => {
+                let reels = match reach {
+                    Some(_) => self.produce_miss(symbol),
+                    None => self.produce_lose(),
+                };
+                (reels, Some(self.symbols(symbol, None)))
+            }
+            LotteryResult::Lose(Lose::Default) => match reach {
+                Some(_) => (self.produce_miss(symbol), None),
+                None => (self.produce_lose(), None),
+            },
+            LotteryResult::Lose(Lose::FakeLose) => {
+                (self.symbols(symbol, None), Some(self.produce_miss(symbol)))
+            }
+        };
+
+        SlotOutcome {
+            reels,
+            reach,
+            revival,
+        }
+    }
+
+    /// Generates the presentation of a lottery result as frames of its stages.
+    ///
+    /// The stages set by [`SlotProducer::with_effects`] are passed in order until one
+    /// is not shown, each shown stage adding a frame. Every stage but the last one
+    /// fails, showing the reels of its [`EffectKind`] that do not win; the last frame
+    /// decides the result. Without any stage shown, a default result is a single frame
+    /// without a stage showing a winning or losing sequence.
+    ///
+    /// Fake results add a frame after the stages:
+    ///
+    /// - `Win::FakeWin`: The last stage fails too, and a `Revival` frame wins
+    ///   (unless the last stage is already a `Reversal` or `Revival`, which wins itself)
+    /// - `Lose::FakeLose`: The last stage shows a win, and a frame without a stage
+    ///   slips off into a loss
+    ///
+    /// # Arguments
+    ///
+    /// * `result` - The lottery result determining what frames to generate
+    ///
+    /// # Returns
+    ///
+    /// The frames in the order they are shown, never empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the producer generates sequences of fewer than 2 symbols and a stage
+    /// is shown or the result is not `Win::Default`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pachislo::{
+    ///     lottery::{LotteryResult, Win},
+    ///     slot::{EffectKind, EffectStage, SlotProducer},
+    /// };
+    /// use rand::rngs::StdRng;
+    ///
+    /// let mut producer = SlotProducer::<_, StdRng>::from_seed(3, vec![1, 2, 3], 7)
+    ///     .with_effects(vec![
+    ///         EffectStage { kind: EffectKind::Reach, win: 1.0, lose: 0.1 },
+    ///         EffectStage { kind: EffectKind::Revival, win: 1.0, lose: 0.0 },
+    ///     ]);
+    ///
+    /// let frames = producer.produce_frames(&LotteryResult::Win(Win::Default));
+    /// assert_eq!(frames[0].stage, Some(EffectKind::Reach));
+    /// assert_ne!(frames[0].reels[1], frames[0].reels[2]);
+    /// assert_eq!(frames[1].stage, Some(EffectKind::Revival));
+    /// assert!(frames[1].reels.iter().all(|&symbol| symbol == frames[1].reels[0]));
+    /// ```
+    pub fn produce_frames(&mut self, result: &LotteryResult) -> Vec<SlotFrame<T>> {
+        self.produce_frames_of(result, None)
+    }
+
+    /// Generates the presentation frames of a jackpot of `category`.
+    ///
+    /// Like [`SlotProducer::produce_frames`], but a winning result only shows symbols
+    /// mapped to `category`, as in [`SlotProducer::produce_jackpot`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if no symbol of `category` can win `result`, or if the producer generates
+    /// sequences of fewer than 2 symbols and a stage is shown or the result is not
+    /// `Win::Default`.
+    pub fn produce_jackpot_frames(
+        &mut self,
+        result: &LotteryResult,
+        category: JackpotCategory,
+    ) -> Vec<SlotFrame<T>> {
+        self.produce_frames_of(result, Some(category))
+    }
+
+    /// Generates the frames of `result`, its winning symbol of `category` if given.
+    fn produce_frames_of(
+        &mut self,
+        result: &LotteryResult,
+        category: Option<JackpotCategory>,
+    ) -> Vec<SlotFrame<T>> {
+        let mut stages = Vec::new();
+        for stage in &self.effects {
+            let probability = if result.is_win() {
+                stage.win
+            } else {
+                stage.lose
+            };
+            if !self.rng.random_bool(probability) {
+                break;
+            }
+            stages.push(stage.kind);
+        }
+
+        let symbol = match result {
+            LotteryResult::Win(win) => self.pick_jackpot(*win, category),
+            LotteryResult::Lose(_) => self.pick(None),
+        };
+        let Some((&last, failed)) = stages.split_last() else {
+            return match result {
+                LotteryResult::Win(Win::Default) => vec![SlotFrame {
+                    stage: None,
+                    reels: self.symbols(symbol, None),
+                }],
+                LotteryResult::Win(Win::FakeWin) => vec![
+                    SlotFrame {
+                        stage: None,
+                        reels: self.produce_lose(),
+                    },
+                    SlotFrame {
+                        stage: Some(EffectKind::Revival),
+                        reels: self.symbols(symbol, None),
+                    },
+                ],
+                LotteryResult::Lose(Lose::Default) => vec![SlotFrame {
+                    stage: None,
+                    reels: self.produce_lose(),
+                }],
+                LotteryResult::Lose(Lose::FakeLose) => vec![
+                    SlotFrame {
+                        stage: None,
+                        reels: self.symbols(symbol, None),
+                    },
+                    SlotFrame {
+                        stage: None,
+                        reels: self.produce_miss(symbol),
+                    },
+                ],
+            };
+        };
+
+        // The reach keeps missing by the same symbol until the last stage
+        let miss = self.produce_miss(symbol);
+
+        let mut frames: Vec<SlotFrame<T>> = Vec::with_capacity(stages.len() + 1);
+        for &stage in failed {
+            let reels = self.failed_stage(stage, symbol, &miss);
+            frames.push(SlotFrame {
+                stage: Some(stage),
+                reels,
+            });
+        }
+        match result {
+            LotteryResult::Win(Win::FakeWin)
+                if !matches!(last, EffectKind::Reversal | EffectKind::Revival) =>
+            {
+                let reels = self.failed_stage(last, symbol, &miss);
+                frames.push(SlotFrame {
+                    stage: Some(last),
+                    reels,
+                });
+                frames.push(SlotFrame {
+                    stage: Some(EffectKind::Revival),
+                    reels: self.symbols(symbol, None),
+                });
+            }
+            LotteryResult::Lose(Lose::Default) => {
+                let reels = self.failed_stage(last, symbol, &miss);
+                frames.push(SlotFrame {
+                    stage: Some(last),
+                    reels,
+                });
+            }
+            LotteryResult::Lose(Lose::FakeLose) => {
+                frames.push(SlotFrame {
+                    stage: Some(last),
+                    reels: self.symbols(symbol, None),
+                });
+                frames.push(SlotFrame {
+                    stage: None,
+                    reels: miss,
+                });
+            }
+            LotteryResult::Win(_) => {
+                frames.push(SlotFrame {
+                    stage: Some(last),
+                    reels: self.symbols(symbol, None),
+                });
+            }
+        }
+        frames
+    }
+
+    /// Returns the reels of a `stage` that does not win, for a reach of `symbol` missing as `miss`.
+    fn failed_stage(&mut self, stage: EffectKind, symbol: usize, miss: &[T]) -> Vec<T> {
+        match stage {
+            EffectKind::Reach | EffectKind::SuperReach => miss.to_vec(),
+            EffectKind::Reversal => {
+                // The last reel shows the reach symbol, the reel before it the missed one
+                let mut reels = self.symbols(symbol, None);
+                reels[self.length - 2] = miss[self.length - 1].clone();
+                reels
+            }
+            EffectKind::Revival => self.produce_lose(),
+        }
+    }
+
+    /// Draws whether a reach is shown and of which kind.
+    fn draw_reach(&mut self, probability: f64, kinds: ReachWeights) -> Option<ReachKind> {
+        if !self.rng.random_bool(probability) {
+            return None;
+        }
+
+        assert!(self.length > 1, "Reaches need at least two reels");
+        let ticket = self.rng.random_range(0..kinds.total());
+        let kind = if ticket < u64::from(kinds.normal) {
+            ReachKind::Normal
+        } else if ticket < u64::from(kinds.normal) + u64::from(kinds.super_) {
+            ReachKind::Super
+        } else {
+            ReachKind::Premium
+        };
+        Some(kind)
+    }
+
+    /// Generates a reach that misses: every reel but the last one shows `symbol`.
+    fn produce_miss(&mut self, symbol: usize) -> Vec<T> {
+        assert!(self.length > 1, "Reaches need at least two reels");
+        let last = if self.reel_weights.is_some() {
+            // Any other symbol, chosen by the reel weights
+            loop {
+                let last = self.pick(None);
+                if last != symbol {
+                    break last;
+                }
+            }
+        } else {
+            // Any other symbol, chosen uniformly
+            let offset = self.rng.random_range(1..self.choices.len());
+            (symbol + offset) % self.choices.len()
+        };
+        self.symbols(symbol, Some(last))
+    }
+
+    /// Returns `length` reels of the symbol at `symbol`, the last one replaced by `last`.
+    fn symbols(&self, symbol: usize, last: Option<usize>) -> Vec<T> {
+        let mut reels = vec![self.choices[symbol].clone(); self.length];
+        if let Some(last) = last {
+            reels[self.length - 1] = self.choices[last].clone();
+        }
+        reels
+    }
 }
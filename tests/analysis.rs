@@ -0,0 +1,434 @@
+use pachislo::{
+    CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE,
+    analysis::{
+        AnalysisError, ReachReliability, analyze, effect_reliability, fake_reliability,
+        outcome_reliability, reach_reliability,
+    },
+    config::{BallsConfig, Config, Payout, Probability, RoundWeight, RushModel, SlotProbability},
+    lottery::{Lose, LotteryResult, Win},
+    simulate::Simulation,
+    slot::{EffectKind, EffectStage, ReachConfig, ReachKind, ReachWeights, SlotProducer},
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+    assert!(
+        (actual - expected).abs() <= tolerance * expected.abs(),
+        "{actual} is not within {tolerance} of {expected}"
+    );
+}
+
+#[test]
+fn rush_without_continuation_lasts_one_round() {
+    let config = Config {
+        probability: Probability {
+            rush_continue: SlotProbability::default(),
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+
+    let analysis = analyze(config, 0.5).unwrap();
+
+    assert_eq!(analysis.chain_distribution, [1.0]);
+    assert_eq!(analysis.expected_chain, 1.0);
+    assert_eq!(analysis.truncated, 0.0);
+
+    // 299 rush launches draw the rush lottery, each winning with 0.5 * 0.48
+    let balls = CONFIG_EXAMPLE.balls;
+    assert_close(analysis.rush_jackpots, 299.0 * 0.5 * 0.48, 1e-12);
+    assert_close(
+        analysis.balls_per_hit,
+        (1.0 + analysis.rush_jackpots) * balls.incremental_balls as f64,
+        1e-12,
+    );
+}
+
+fn constant_curve(_: usize) -> f64 {
+    1.0
+}
+
+#[test]
+fn endless_rush_is_truncated() {
+    // 299 rush launches per round, each continuing with 0.5 * 0.48: the balls only grow
+    let config = Config {
+        probability: Probability {
+            rush_continue: SlotProbability {
+                win: 1.0,
+                ..CONFIG_EXAMPLE.probability.rush_continue
+            },
+            rush_continue_fn: constant_curve as fn(usize) -> f64,
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+
+    let analysis = analyze(config, 0.5).unwrap();
+
+    assert_close(analysis.truncated, 1.0, 1e-9);
+    let total: f64 = analysis.chain_distribution.iter().sum();
+    assert_close(total + analysis.truncated, 1.0, 1e-9);
+    assert!(analysis.chain_distribution.len() < 1_000);
+}
+
+#[test]
+fn special_times_chain_is_geometric() {
+    let config = Config {
+        balls: BallsConfig {
+            rush_model: RushModel::SpecialTimes { spins: 2 },
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            rush: SlotProbability {
+                win: 0.5,
+                ..CONFIG_EXAMPLE.probability.rush
+            },
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+
+    let analysis = analyze(config, START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+
+    // The rush continues with 1 - 0.5^2 = 0.75
+    assert_close(analysis.chain_distribution[0], 0.25, 1e-12);
+    assert_close(analysis.chain_distribution[1], 0.75 * 0.25, 1e-12);
+    assert_close(analysis.expected_chain, 4.0, 1e-9);
+    assert_close(analysis.rush_jackpots, 3.0, 1e-9);
+}
+
+#[test]
+fn bonus_pays_expected_rounds() {
+    let config = Config {
+        balls: BallsConfig {
+            hold_capacity: 0,
+            ..CONFIG_EXAMPLE.balls
+        },
+        payout: Some(Payout {
+            normal: vec![
+                RoundWeight {
+                    rounds: 4,
+                    weight: 3,
+                },
+                RoundWeight {
+                    rounds: 16,
+                    weight: 1,
+                },
+            ],
+            rush: vec![RoundWeight {
+                rounds: 10,
+                weight: 1,
+            }],
+            counts: 10,
+            balls_per_count: 15,
+        }),
+        ..CONFIG_EXAMPLE
+    };
+
+    let analysis = analyze(config, START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+
+    // 7 rounds on average, each round feeding 10 balls that pay 14 net
+    assert_close(analysis.balls_per_jackpot, 7.0 * 10.0 * 14.0, 1e-12);
+    assert_close(analysis.balls_per_rush_jackpot, 10.0 * 10.0 * 14.0, 1e-12);
+}
+
+#[test]
+fn unsupported_mechanics_are_rejected() {
+    let config = Config {
+        balls: BallsConfig {
+            spin_duration: 3,
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+    assert_eq!(
+        analyze(config, START_HOLE_PROBABILITY_EXAMPLE),
+        Err(AnalysisError::Unsupported("spin_duration (hold timing)"))
+    );
+
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 0,
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+    assert!(matches!(
+        analyze(config, START_HOLE_PROBABILITY_EXAMPLE),
+        Err(AnalysisError::Config(_))
+    ));
+}
+
+#[test]
+fn simulation_agrees_with_analysis() {
+    let analysis = analyze(CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+
+    let report = Simulation {
+        start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+        launches: 1_000_000,
+        sessions: 8,
+        seed: Some(12),
+    }
+    .run(&CONFIG_EXAMPLE)
+    .unwrap();
+
+    assert_close(report.average_rush_length(), analysis.expected_chain, 0.02);
+    assert_close(report.payout_ratio(), analysis.payout_rate, 0.02);
+}
+
+#[test]
+fn simulation_agrees_with_special_times_analysis() {
+    let config = Config {
+        balls: BallsConfig {
+            hold_capacity: 0,
+            rush_model: RushModel::SpecialTimes { spins: 8 },
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            rush: SlotProbability {
+                win: 0.2,
+                ..CONFIG_EXAMPLE.probability.rush
+            },
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: Some(Payout {
+            normal: vec![RoundWeight {
+                rounds: 3,
+                weight: 1,
+            }],
+            rush: vec![
+                RoundWeight {
+                    rounds: 3,
+                    weight: 1,
+                },
+                RoundWeight {
+                    rounds: 10,
+                    weight: 1,
+                },
+            ],
+            counts: 10,
+            balls_per_count: 3,
+        }),
+    };
+    let analysis = analyze(config.clone(), START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+
+    let report = Simulation {
+        start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+        launches: 1_000_000,
+        sessions: 8,
+        seed: Some(12),
+    }
+    .run(&config)
+    .unwrap();
+
+    assert_close(report.average_rush_length(), analysis.expected_chain, 0.02);
+    assert_close(report.payout_ratio(), analysis.payout_rate, 0.02);
+}
+
+#[test]
+fn simulation_agrees_with_partial_rush_entry() {
+    let config = Config {
+        probability: Probability {
+            rush_entry: 0.4,
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+    let analysis = analyze(config.clone(), START_HOLE_PROBABILITY_EXAMPLE).unwrap();
+
+    let report = Simulation {
+        start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+        launches: 1_000_000,
+        sessions: 8,
+        seed: Some(12),
+    }
+    .run(&config)
+    .unwrap();
+
+    assert_close(report.payout_ratio(), analysis.payout_rate, 0.02);
+}
+
+#[test]
+fn fake_reliability_splits_by_first_reels() {
+    let probability = SlotProbability {
+        win: 0.5,
+        fake_win: 0.2,
+        fake_lose: 0.1,
+    };
+
+    let reliability = fake_reliability(probability).unwrap();
+
+    assert_close(reliability.win_shown.frequency, 0.45, 1e-12);
+    assert_close(reliability.win_shown.win_rate.unwrap(), 0.4 / 0.45, 1e-12);
+    assert_close(reliability.lose_shown.frequency, 0.55, 1e-12);
+    assert_close(reliability.lose_shown.win_rate.unwrap(), 0.1 / 0.55, 1e-12);
+
+    let never = fake_reliability(SlotProbability::default()).unwrap();
+    assert_eq!(never.win_shown.win_rate, None);
+    assert!(matches!(
+        fake_reliability(SlotProbability {
+            fake_win: 2.0,
+            ..probability
+        }),
+        Err(AnalysisError::Config(_))
+    ));
+}
+
+#[test]
+fn reach_reliability_weighs_kinds() {
+    let probability = SlotProbability {
+        win: 0.2,
+        ..SlotProbability::default()
+    };
+    let reach = ReachConfig {
+        win: 0.5,
+        lose: 0.25,
+        win_kinds: ReachWeights {
+            normal: 1,
+            super_: 2,
+            premium: 1,
+        },
+        lose_kinds: ReachWeights {
+            normal: 3,
+            super_: 1,
+            premium: 0,
+        },
+    };
+
+    let reliability = reach_reliability(probability, reach).unwrap();
+
+    assert_close(reliability.none.win_rate.unwrap(), 0.1 / 0.7, 1e-12);
+    assert_close(reliability.normal.win_rate.unwrap(), 0.025 / 0.175, 1e-12);
+    assert_close(reliability.super_.win_rate.unwrap(), 0.05 / 0.1, 1e-12);
+    assert_eq!(reliability.premium.win_rate, Some(1.0));
+    let total = reliability.none.frequency
+        + reliability.normal.frequency
+        + reliability.super_.frequency
+        + reliability.premium.frequency;
+    assert_close(total, 1.0, 1e-12);
+}
+
+#[test]
+fn outcome_reliability_matches_produced_outcomes() {
+    let probability = SlotProbability {
+        win: 0.2,
+        fake_win: 0.3,
+        fake_lose: 0.1,
+    };
+    let reach = ReachConfig {
+        win: 0.8,
+        lose: 0.3,
+        win_kinds: ReachWeights {
+            normal: 1,
+            super_: 2,
+            premium: 1,
+        },
+        lose_kinds: ReachWeights {
+            normal: 3,
+            super_: 1,
+            premium: 0,
+        },
+    };
+
+    let reliability = outcome_reliability(probability, reach).unwrap();
+    let row = |table: &ReachReliability| [table.none, table.normal, table.super_, table.premium];
+    let cells: Vec<_> = row(&reliability.plain)
+        .into_iter()
+        .chain(row(&reliability.revived))
+        .collect();
+    let total: f64 = cells.iter().map(|cell| cell.frequency).sum();
+    assert_close(total, 1.0, 1e-12);
+
+    let mut lottery = StdRng::seed_from_u64(9);
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 9).with_reach(reach);
+    let draws = 200_000;
+    let mut shown = [(0, 0); 8];
+    for _ in 0..draws {
+        let result = if lottery.random_bool(probability.win) {
+            if lottery.random_bool(probability.fake_win) {
+                LotteryResult::Win(Win::FakeWin)
+            } else {
+                LotteryResult::Win(Win::Default)
+            }
+        } else if lottery.random_bool(probability.fake_lose) {
+            LotteryResult::Lose(Lose::FakeLose)
+        } else {
+            LotteryResult::Lose(Lose::Default)
+        };
+        let outcome = producer.produce_outcome(&result);
+        let kind = match outcome.reach {
+            None => 0,
+            Some(ReachKind::Normal) => 1,
+            Some(ReachKind::Super) => 2,
+            Some(ReachKind::Premium) => 3,
+        };
+        let count = &mut shown[kind + 4 * usize::from(outcome.revival.is_some())];
+        count.0 += 1;
+        count.1 += usize::from(result.is_win());
+    }
+
+    for (cell, (count, wins)) in cells.iter().zip(shown) {
+        assert_close(count as f64 / draws as f64, cell.frequency, 0.1);
+        if let Some(win_rate) = cell.win_rate {
+            assert_close(wins as f64 / count as f64, win_rate, 0.1);
+        }
+    }
+}
+
+#[test]
+fn effect_reliability_matches_produced_frames() {
+    let probability = SlotProbability {
+        win: 0.2,
+        ..SlotProbability::default()
+    };
+    let stages = [
+        EffectStage {
+            kind: EffectKind::Reach,
+            win: 0.9,
+            lose: 0.3,
+        },
+        EffectStage {
+            kind: EffectKind::SuperReach,
+            win: 0.6,
+            lose: 0.2,
+        },
+        EffectStage {
+            kind: EffectKind::Revival,
+            win: 0.5,
+            lose: 0.0,
+        },
+    ];
+
+    let reliability = effect_reliability(probability, &stages).unwrap();
+    assert_eq!(reliability[2].win_rate, Some(1.0));
+
+    let mut lottery = StdRng::seed_from_u64(5);
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 5).with_effects(stages.to_vec());
+    let draws = 100_000;
+    let mut shown = [(0, 0); 3];
+    for _ in 0..draws {
+        let result = if lottery.random_bool(probability.win) {
+            LotteryResult::Win(Win::Default)
+        } else {
+            LotteryResult::Lose(Lose::Default)
+        };
+        let frames = producer.produce_frames(&result);
+        for (count, frame) in shown.iter_mut().zip(&frames) {
+            if frame.stage.is_some() {
+                count.0 += 1;
+                count.1 += usize::from(result.is_win());
+            }
+        }
+    }
+
+    for (reliability, (count, wins)) in reliability.iter().zip(shown) {
+        assert_close(count as f64 / draws as f64, reliability.frequency, 0.05);
+        assert_close(
+            wins as f64 / count as f64,
+            reliability.win_rate.unwrap(),
+            0.05,
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{BallsConfig, Config, Probability, RushModel},
+    event::GameEvent,
+    game::{Game, GameBuilder, GameState, Hold},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::{ALWAYS, EventLog, NullInput};
+
+fn rush(rush_balls: usize, n: usize) -> GameState {
+    GameState::Rush {
+        balls: 500,
+        rush_balls,
+        n,
+    }
+}
+
+#[test]
+fn builder_defaults_to_a_started_game() {
+    let mut game = Game::builder(CONFIG_EXAMPLE, NullInput, EventLog { events: Vec::new() })
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: CONFIG_EXAMPLE.balls.init_balls,
+            spins: 0
+        }
+    );
+    assert!(game.start().is_err());
+}
+
+#[test]
+fn rush_continues_from_a_built_state() {
+    let config: Config = Config {
+        probability: Probability {
+            rush: ALWAYS,
+            rush_continue: ALWAYS,
+            rush_continue_fn: |_| 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+    let output = EventLog { events: Vec::new() };
+    let mut game = GameBuilder::with_rng(config, NullInput, output, StdRng::seed_from_u64(0))
+        .state(rush(1, 7))
+        .build()
+        .unwrap();
+    game.enter_start_hole().unwrap();
+
+    let balls = CONFIG_EXAMPLE.balls;
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: 500 + balls.incremental_balls,
+            rush_balls: 1 + balls.incremental_rush,
+            n: 8,
+        }
+    );
+    assert!(
+        game.output()
+            .events
+            .contains(&GameEvent::RushContinued { n: 8 })
+    );
+}
+
+#[test]
+fn rush_is_exhausted_by_its_last_ball() {
+    let config = Config {
+        balls: BallsConfig {
+            jitan_spins: 20,
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+    let mut game = Game::builder(config, NullInput, EventLog { events: Vec::new() })
+        .state(rush(1, 7))
+        .build()
+        .unwrap();
+    game.launch_ball().unwrap();
+
+    assert_eq!(
+        *game.state(),
+        GameState::Jitan {
+            balls: 500,
+            remaining_spins: 20
+        }
+    );
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::BallLaunched { balls: 500 },
+            GameEvent::RushEnded { n: 7, gained: 0 },
+            GameEvent::JitanEntered { spins: 20 },
+        ]
+    );
+}
+
+#[test]
+fn builder_rejects_states_impossible_with_the_config() {
+    let build = |config: Config, state, hold| {
+        Game::builder(config, NullInput, EventLog { events: Vec::new() })
+            .state(state)
+            .hold(hold)
+            .build()
+            .err()
+            .map(|error| error.errors().to_vec())
+            .unwrap_or_default()
+    };
+
+    assert_eq!(
+        build(CONFIG_EXAMPLE, rush(0, 0), Hold::default()),
+        [
+            "state.rush_balls: must be greater than 0",
+            "state.n: must be greater than 0",
+        ]
+    );
+    assert_eq!(
+        build(
+            CONFIG_EXAMPLE,
+            GameState::SpecialTimes {
+                balls: 500,
+                remaining_spins: 10,
+                n: 1
+            },
+            Hold {
+                pending: 5,
+                spin_remaining: 0,
+                wasted: 0
+            }
+        ),
+        [
+            "state: SpecialTimes requires rush_model SpecialTimes",
+            "hold.pending: must not exceed hold_capacity (4)",
+        ]
+    );
+
+    let special_times = Config {
+        balls: BallsConfig {
+            rush_model: RushModel::SpecialTimes { spins: 100 },
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+    assert_eq!(
+        build(special_times.clone(), rush(10, 1), Hold::default()),
+        ["state: Rush requires rush_model ContinueLottery"]
+    );
+    assert_eq!(
+        build(
+            special_times,
+            GameState::SpecialTimes {
+                balls: 0,
+                remaining_spins: 101,
+                n: 1
+            },
+            Hold::default()
+        ),
+        [
+            "state.balls: must be greater than 0",
+            "state.remaining_spins: must be between 1 and rush_model.spins (100)",
+        ]
+    );
+    assert!(build(CONFIG_EXAMPLE, GameState::Uninitialized, Hold::default()).is_empty());
+}
@@ -0,0 +1,142 @@
+use std::ops::ControlFlow;
+
+use pachislo::{
+    CONFIG_EXAMPLE,
+    command::{Command, FinishGame, LaunchBall, LaunchBallFlow, StartGame},
+    config::{BallsConfig, Config},
+    event::GameEvent,
+    game::{AlreadyStartedError, Game, GameError, GameState, Transition, UninitializedError},
+    interface::{UserInput, UserOutput},
+    lottery::LotteryResult,
+};
+
+mod common;
+
+use common::{EventLog, NullInput};
+
+fn game() -> Game<NullInput, EventLog> {
+    Game::new(CONFIG_EXAMPLE, NullInput, EventLog { events: Vec::new() }).unwrap()
+}
+
+#[test]
+fn rejected_commands_are_reported() {
+    let mut game = game();
+
+    assert_eq!(
+        game.run_step_with_command(Command::control(LaunchBall)),
+        Err(GameError::Uninitialized(UninitializedError))
+    );
+    assert_eq!(
+        game.run_step_with_command(Command::control(FinishGame)),
+        Err(GameError::Uninitialized(UninitializedError))
+    );
+    assert_eq!(*game.state(), GameState::Uninitialized);
+
+    assert_eq!(
+        game.run_step_with_command(Command::control(StartGame)),
+        Ok(ControlFlow::Continue(()))
+    );
+    assert_eq!(
+        game.run_step_with_command(Command::control(StartGame)),
+        Err(GameError::AlreadyStarted(AlreadyStartedError))
+    );
+
+    let rejected: Vec<_> = game
+        .output()
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::CommandRejected(error) => Some(*error),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        rejected,
+        [
+            GameError::Uninitialized(UninitializedError),
+            GameError::Uninitialized(UninitializedError),
+            GameError::AlreadyStarted(AlreadyStartedError),
+        ]
+    );
+}
+
+#[test]
+fn last_ball_is_not_rejected() {
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 1,
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+    let mut game: Game<_, _> =
+        Game::new(config, NullInput, EventLog { events: Vec::new() }).unwrap();
+    game.start().unwrap();
+
+    assert_eq!(
+        game.run_step_with_command(Command::control(LaunchBallFlow::new(true))),
+        Ok(ControlFlow::Continue(()))
+    );
+    assert_eq!(*game.state(), GameState::Uninitialized);
+}
+
+/// Launches a ball before starting the game, then starts it.
+struct EagerInput {
+    commands: Vec<Command<Self, EventLog>>,
+}
+
+impl UserInput<EventLog> for EagerInput {
+    fn wait_for_input(&mut self) -> Command<Self, EventLog> {
+        self.commands.pop().unwrap_or(Command::FinishGame)
+    }
+}
+
+#[test]
+fn game_loop_survives_rejected_commands() {
+    let input = EagerInput {
+        commands: vec![Command::control(StartGame), Command::control(LaunchBall)],
+    };
+    let mut game = Game::new(CONFIG_EXAMPLE, input, EventLog { events: Vec::new() }).unwrap();
+    game.run();
+
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::CommandRejected(GameError::Uninitialized(UninitializedError)),
+            GameEvent::GameStarted {
+                balls: CONFIG_EXAMPLE.balls.init_balls
+            },
+        ]
+    );
+}
+
+/// Output recording rejections through the callback.
+struct Rejections(Vec<GameError>);
+
+impl UserOutput for Rejections {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush_continue(&mut self, _result: LotteryResult) {}
+
+    fn command_rejected(&mut self, error: GameError) {
+        self.0.push(error);
+    }
+}
+
+#[test]
+fn rejections_reach_the_output_callback() {
+    let mut game: Game<_, _> =
+        Game::new(CONFIG_EXAMPLE, NullInput, Rejections(Vec::new())).unwrap();
+    let _ = game.run_step_with_command(Command::control(LaunchBall));
+
+    assert_eq!(
+        game.output().0,
+        [GameError::Uninitialized(UninitializedError)]
+    );
+}
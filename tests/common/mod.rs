@@ -0,0 +1,121 @@
+#![allow(dead_code)]
+
+use pachislo::{
+    command::Command,
+    config::{Ceiling, RushContinueFn, SlotProbability},
+    event::{EventHandler, GameEvent},
+    game::{Bonus, GameState, Transition},
+    interface::{UserInput, UserOutput},
+    lottery::{Lose, LotteryResult, LotterySource, ProbabilityError, Win},
+};
+
+/// Lottery that always wins, without fake results.
+pub const ALWAYS: SlotProbability = SlotProbability {
+    win: 1.0,
+    fake_win: 0.0,
+    fake_lose: 0.0,
+};
+
+/// Lottery that never wins, without fake results.
+pub const NEVER: SlotProbability = SlotProbability {
+    win: 0.0,
+    fake_win: 0.0,
+    fake_lose: 0.0,
+};
+
+/// Plain winning lottery result.
+pub const WIN: LotteryResult = LotteryResult::Win(Win::Default);
+
+/// Plain losing lottery result.
+pub const LOSE: LotteryResult = LotteryResult::Lose(Lose::Default);
+
+/// Input that immediately ends the game loop, for games drawing from any source.
+pub struct NullInput;
+
+impl<O: UserOutput, F: RushContinueFn, R: LotterySource> UserInput<O, F, R> for NullInput {
+    fn wait_for_input(&mut self) -> Command<Self, O, F, R> {
+        Command::FinishGame
+    }
+}
+
+/// Output that ignores every callback.
+pub struct NullOutput;
+
+impl UserOutput for NullOutput {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush_continue(&mut self, _result: LotteryResult) {}
+}
+
+/// Output that records every game event.
+#[derive(Default)]
+pub struct EventLog {
+    pub events: Vec<GameEvent>,
+}
+
+impl EventHandler for EventLog {
+    fn handle_event(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+}
+
+/// A single callback received by [`RecordingOutput`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Record {
+    Finish(GameState),
+    Normal(LotteryResult),
+    Rush(LotteryResult),
+    RushContinue(LotteryResult),
+    Kakuhen(LotteryResult),
+    ProbabilityError,
+    BonusRound(Bonus),
+    Ceiling(Ceiling),
+}
+
+/// Output that records every lottery related callback.
+#[derive(Default)]
+pub struct RecordingOutput {
+    pub records: Vec<Record>,
+}
+
+impl UserOutput for RecordingOutput {
+    fn default(&mut self, _state: Transition) {}
+
+    fn finish_game(&mut self, state: &GameState) {
+        self.records.push(Record::Finish(*state));
+    }
+
+    fn lottery_normal(&mut self, result: LotteryResult) {
+        self.records.push(Record::Normal(result));
+    }
+
+    fn lottery_rush(&mut self, result: LotteryResult) {
+        self.records.push(Record::Rush(result));
+    }
+
+    fn lottery_rush_continue(&mut self, result: LotteryResult) {
+        self.records.push(Record::RushContinue(result));
+    }
+
+    fn lottery_kakuhen(&mut self, result: LotteryResult) {
+        self.records.push(Record::Kakuhen(result));
+    }
+
+    fn probability_error(&mut self, _error: ProbabilityError) {
+        self.records.push(Record::ProbabilityError);
+    }
+
+    fn bonus_round(&mut self, bonus: Bonus) {
+        self.records.push(Record::BonusRound(bonus));
+    }
+
+    fn ceiling(&mut self, ceiling: Ceiling) {
+        self.records.push(Record::Ceiling(ceiling));
+    }
+}
@@ -0,0 +1,308 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{
+        BallsConfig, Ceiling, CeilingReward, Config, Payout, Probability, RoundWeight, RushModel,
+        SlotProbability,
+    },
+    game::Game,
+};
+
+mod common;
+
+use common::{NullInput, NullOutput};
+
+#[test]
+fn validation_errors_carry_field_paths() {
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 0,
+            rush_model: RushModel::SpecialTimes { spins: 0 },
+            ceiling: Some(Ceiling {
+                spins: 0,
+                reward: CeilingReward::Rush,
+            }),
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            rush: SlotProbability {
+                win: 1.5,
+                ..CONFIG_EXAMPLE.probability.rush
+            },
+            rush_continue: SlotProbability {
+                fake_lose: -0.1,
+                ..CONFIG_EXAMPLE.probability.rush_continue
+            },
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let Err(error) = Game::<_, _>::new(config, NullInput, NullOutput) else {
+        panic!("invalid config was accepted");
+    };
+
+    assert_eq!(
+        error.errors(),
+        [
+            "balls.init_balls: initial balls must be greater than 0",
+            "balls.rush_model.spins: must be greater than 0",
+            "balls.ceiling.spins: must be greater than 0",
+            "probability.rush.win: probability must be between 0.0 and 1.0",
+            "probability.rush_continue.fake_lose: probability must be between 0.0 and 1.0",
+        ]
+    );
+}
+
+#[test]
+fn nan_probabilities_are_rejected() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability::new(
+            SlotProbability {
+                win: f64::NAN,
+                ..SlotProbability::default()
+            },
+            CONFIG_EXAMPLE.probability.rush,
+            SlotProbability {
+                fake_win: f64::NAN,
+                ..CONFIG_EXAMPLE.probability.rush_continue
+            },
+            CONFIG_EXAMPLE.probability.rush_continue_fn,
+        ),
+        payout: None,
+    };
+
+    let Err(error) = Game::<_, _>::new(config, NullInput, NullOutput) else {
+        panic!("NaN probabilities were accepted");
+    };
+
+    assert_eq!(
+        error.errors(),
+        [
+            "probability.normal.win: probability must be between 0.0 and 1.0",
+            "probability.rush_continue.fake_win: probability must be between 0.0 and 1.0",
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn deserialize_sections() {
+    let balls: BallsConfig = toml::from_str(
+        r#"
+        init_balls = 250
+        incremental_balls = 10
+        incremental_rush = 100
+        "#,
+    )
+    .unwrap();
+    assert_eq!(balls.init_balls, 250);
+    assert_eq!(balls.incremental_rush, 100);
+    assert_eq!(balls.rush_model, RushModel::ContinueLottery);
+
+    let balls: BallsConfig = toml::from_str(
+        r#"
+        init_balls = 250
+        incremental_balls = 10
+        incremental_rush = 0
+        rush_model = { special_times = { spins = 50 } }
+        "#,
+    )
+    .unwrap();
+    assert_eq!(balls.rush_model, RushModel::SpecialTimes { spins: 50 });
+
+    let probability: SlotProbability =
+        serde_json::from_str(r#"{ "win": 0.01, "fake_win": 0.5, "fake_lose": 0.02 }"#).unwrap();
+    assert_eq!(probability.win, 0.01);
+
+    let unknown = toml::from_str::<SlotProbability>("win = 0.1\nfake_win = 0.1\nfake_los = 0.1");
+    assert!(unknown.is_err());
+}
+
+#[cfg(feature = "serde")]
+const MACHINE_TOML: &str = r#"
+[balls]
+init_balls = 1000
+incremental_balls = 15
+incremental_rush = 300
+
+[probability]
+rush_continue_fn = { geometric = { ratio = 0.6 } }
+
+[probability.normal]
+win = 0.16
+fake_win = 0.3
+fake_lose = 0.15
+
+[probability.rush]
+win = 0.48
+fake_win = 0.2
+fake_lose = 0.05
+
+[probability.rush_continue]
+win = 0.8
+fake_win = 0.25
+fake_lose = 0.1
+"#;
+
+#[cfg(feature = "serde")]
+#[test]
+fn load_toml_with_continue_curve() {
+    use pachislo::curve::ContinueCurve;
+
+    let config = Config::<ContinueCurve>::from_toml_str(MACHINE_TOML).unwrap();
+
+    assert_eq!(
+        config.probability.rush_continue_fn,
+        ContinueCurve::Geometric { ratio: 0.6 }
+    );
+    assert_eq!(config.balls.incremental_rush, 300);
+    // Omitted fields get the same defaults as `Probability::new`
+    assert_eq!(
+        config.probability.jitan_start_hole,
+        CONFIG_EXAMPLE.probability.jitan_start_hole
+    );
+
+    let json = serde_json::to_string(&config).unwrap();
+    let reloaded = Config::<ContinueCurve>::from_json_str(&json).unwrap();
+    assert_eq!(
+        reloaded.probability.rush_continue_fn,
+        config.probability.rush_continue_fn
+    );
+
+    assert!(Game::<_, _, _>::new(config, NullInput, NullOutput).is_ok());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn loader_reports_every_error() {
+    use pachislo::curve::ContinueCurve;
+
+    let toml = MACHINE_TOML
+        .replace("init_balls = 1000", "init_balls = 0")
+        .replace("win = 0.48", "win = 4.8");
+
+    let error = Config::<ContinueCurve>::from_toml_str(&toml).unwrap_err();
+    assert_eq!(
+        error.errors(),
+        [
+            "balls.init_balls: initial balls must be greater than 0",
+            "probability.rush.win: probability must be between 0.0 and 1.0",
+        ]
+    );
+
+    let error = Config::<ContinueCurve>::from_toml_str("[balls]\ninit_balls = 1").unwrap_err();
+    assert_eq!(error.errors().len(), 1);
+}
+
+fn bad_curve(n: usize) -> f64 {
+    match n {
+        1 => 0.9,
+        2 => 1.2,
+        _ => 0.5,
+    }
+}
+
+fn increasing_late(n: usize) -> f64 {
+    if n < 50 { 1.0 } else { 0.5 + n as f64 / 1000.0 }
+}
+
+#[test]
+fn rush_continue_fn_is_validated() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            rush_continue_fn: bad_curve as fn(usize) -> f64,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let Err(error) = Game::<_, _>::new(config, NullInput, NullOutput) else {
+        panic!("invalid rush_continue_fn was accepted");
+    };
+
+    assert_eq!(
+        error.errors(),
+        [
+            "probability.rush_continue_fn: must return 1.0 for n = 1 (got 0.9)",
+            "probability.rush_continue_fn: must be between 0.0 and 1.0 (got 1.2 for n = 2)",
+            "probability.rush_continue_fn: must be monotonically non-increasing (got 0.9 for n = 1 and 1.2 for n = 2)",
+        ]
+    );
+}
+
+#[test]
+fn rush_continue_fn_is_validated_up_to_horizon() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            rush_continue_fn: increasing_late as fn(usize) -> f64,
+            rush_continue_horizon: 49,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    assert!(Game::<_, _>::new(config, NullInput, NullOutput).is_ok());
+
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            rush_continue_fn: increasing_late as fn(usize) -> f64,
+            rush_continue_horizon: 60,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    assert!(Game::<_, _>::new(config, NullInput, NullOutput).is_err());
+}
+
+#[test]
+fn payout_is_validated() {
+    let config = Config {
+        payout: Some(Payout {
+            normal: vec![RoundWeight {
+                rounds: 10,
+                weight: 0,
+            }],
+            rush: vec![RoundWeight {
+                rounds: 0,
+                weight: 1,
+            }],
+            counts: 0,
+            balls_per_count: 15,
+        }),
+        ..CONFIG_EXAMPLE
+    };
+
+    let Err(error) = Game::<_, _>::new(config, NullInput, NullOutput) else {
+        panic!("invalid payout was accepted");
+    };
+
+    assert_eq!(
+        error.errors(),
+        [
+            "payout.normal: round table must contain an entry with a positive weight",
+            "payout.rush.rounds: must be greater than 0",
+            "payout.counts: must be greater than 0",
+        ]
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn loader_accepts_odds_notation() {
+    use pachislo::curve::ContinueCurve;
+
+    let toml = MACHINE_TOML
+        .replace("win = 0.16", r#"win = "1/319.7""#)
+        .replace("fake_lose = 0.05", r#"fake_lose = "5%""#);
+
+    let config = Config::<ContinueCurve>::from_toml_str(&toml).unwrap();
+    assert_eq!(config.probability.normal.win, 1.0 / 319.7);
+    assert_eq!(config.probability.rush.fake_lose, 0.05);
+
+    let error =
+        Config::<ContinueCurve>::from_toml_str(&toml.replace("1/319.7", "1/0")).unwrap_err();
+    assert!(error.errors()[0].contains("denominator must be greater than 0"));
+}
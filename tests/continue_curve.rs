@@ -0,0 +1,76 @@
+use pachislo::{
+    CONFIG_EXAMPLE, config::Config, config::Probability, curve::ContinueCurve, game::Game,
+};
+
+mod common;
+
+use common::{NullInput, NullOutput};
+
+#[test]
+fn curve_values() {
+    assert_eq!(ContinueCurve::Constant.values(3), [1.0, 1.0, 1.0]);
+    assert_eq!(
+        ContinueCurve::Linear {
+            step: 0.25,
+            floor: 0.4
+        }
+        .values(4),
+        [1.0, 0.75, 0.5, 0.4]
+    );
+    assert_eq!(
+        ContinueCurve::Table(vec![1.0, 0.8, 0.5]).values(5),
+        [1.0, 0.8, 0.5, 0.5, 0.5]
+    );
+    assert_eq!(
+        ContinueCurve::Step {
+            thresholds: vec![(3, 0.7), (5, 0.2)]
+        }
+        .values(6),
+        [1.0, 1.0, 0.7, 0.7, 0.2, 0.2]
+    );
+}
+
+#[test]
+fn curve_display() {
+    assert_eq!(ContinueCurve::Constant.to_string(), "1");
+    assert_eq!(
+        ContinueCurve::Linear {
+            step: 0.1,
+            floor: 0.3
+        }
+        .to_string(),
+        "max(1 - 0.1(n-1), 0.3)"
+    );
+    assert_eq!(ContinueCurve::Table(vec![1.0, 0.5]).to_string(), "[1, 0.5]");
+    assert_eq!(
+        ContinueCurve::Step {
+            thresholds: vec![(4, 0.5)]
+        }
+        .to_string(),
+        "1; 0.5 (n >= 4)"
+    );
+}
+
+#[test]
+fn curve_as_config_function() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            rush_continue_fn: ContinueCurve::Geometric { ratio: 0.6 },
+            normal: CONFIG_EXAMPLE.probability.normal,
+            rush: CONFIG_EXAMPLE.probability.rush,
+            rush_continue: CONFIG_EXAMPLE.probability.rush_continue,
+            rush_continue_horizon: 100,
+            kakuhen: CONFIG_EXAMPLE.probability.kakuhen,
+            kakuhen_entry: 0.0,
+            kakuhen_fall: 0.0,
+            rush_entry: 1.0,
+            jitan_start_hole: 0.0,
+        },
+        payout: None,
+    };
+
+    let mut game: Game<_, _, _> = Game::new(config, NullInput, NullOutput).unwrap();
+    game.start().unwrap();
+    game.cause_lottery();
+}
@@ -0,0 +1,239 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{BallsConfig, Config, Probability},
+    event::{GameEvent, LotteryMode},
+    game::{Game, Hold},
+    lottery::{JackpotCategory, Lose, LotteryResult, ScriptedLottery, Win},
+};
+
+mod common;
+
+use common::{ALWAYS, EventLog, LOSE, NEVER, NullInput, WIN};
+
+#[test]
+fn rush_is_reported_from_entry_to_end() {
+    let config = Config {
+        balls: BallsConfig {
+            incremental_rush: 2,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: ALWAYS,
+            rush: ALWAYS,
+            rush_continue: ALWAYS,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, EventLog::default()).unwrap();
+    game.start().unwrap();
+    game.enter_start_hole().unwrap();
+    game.enter_start_hole().unwrap();
+    // Two rush balls per round
+    for _ in 0..4 {
+        game.launch_ball().unwrap();
+    }
+    game.finish().unwrap();
+
+    let win = LotteryResult::Win(Win::Default);
+    let after_rush = balls.init_balls + 2 * balls.incremental_balls;
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::GameStarted {
+                balls: balls.init_balls
+            },
+            GameEvent::StartHoleEntered {
+                hold: Hold::default()
+            },
+            GameEvent::JackpotDecided(JackpotCategory::Rush),
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: win,
+            },
+            GameEvent::RushEntered,
+            GameEvent::BallsAwarded {
+                balls: balls.incremental_balls
+            },
+            GameEvent::StartHoleEntered {
+                hold: Hold::default()
+            },
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Rush,
+                result: win,
+            },
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::RushContinue,
+                result: win,
+            },
+            GameEvent::RushContinued { n: 2 },
+            GameEvent::BallsAwarded {
+                balls: balls.incremental_balls
+            },
+            GameEvent::BallLaunched { balls: after_rush },
+            GameEvent::BallLaunched { balls: after_rush },
+            GameEvent::BallLaunched { balls: after_rush },
+            GameEvent::BallLaunched { balls: after_rush },
+            GameEvent::RushEnded {
+                n: 2,
+                gained: 2 * balls.incremental_balls,
+            },
+            GameEvent::GameFinished { balls: after_rush },
+        ]
+    );
+}
+
+#[test]
+fn jitan_is_reported_from_entry_to_end() {
+    let config = Config {
+        balls: BallsConfig {
+            jitan_spins: 2,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            rush_entry: 0.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let script = ScriptedLottery::new().normal([WIN, LOSE, LOSE]);
+    let mut game = Game::builder(config, NullInput, EventLog::default())
+        .rng(script)
+        .build()
+        .unwrap();
+
+    // The jackpot misses the rush and continues in jitan until its spins run out
+    for _ in 0..3 {
+        game.cause_lottery();
+    }
+
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::JackpotDecided(JackpotCategory::Normal),
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: WIN,
+            },
+            GameEvent::JitanEntered { spins: 2 },
+            GameEvent::BallsAwarded {
+                balls: CONFIG_EXAMPLE.balls.incremental_balls
+            },
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: LOSE,
+            },
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: LOSE,
+            },
+            GameEvent::JitanEnded,
+        ]
+    );
+}
+
+#[test]
+fn kakuhen_is_reported_from_entry_to_end() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            kakuhen_entry: 1.0,
+            kakuhen_fall: 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let script = ScriptedLottery::new().normal([WIN]).kakuhen([WIN, LOSE]);
+    let mut game = Game::builder(config, NullInput, EventLog::default())
+        .rng(script)
+        .build()
+        .unwrap();
+
+    // Two jackpots in a row, then the kakuhen falls back to normal mode
+    for _ in 0..3 {
+        game.cause_lottery();
+    }
+
+    let balls_awarded = GameEvent::BallsAwarded {
+        balls: CONFIG_EXAMPLE.balls.incremental_balls,
+    };
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::JackpotDecided(JackpotCategory::Kakuhen),
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: WIN,
+            },
+            GameEvent::KakuhenEntered,
+            balls_awarded,
+            GameEvent::JackpotDecided(JackpotCategory::Kakuhen),
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Kakuhen,
+                result: WIN,
+            },
+            balls_awarded,
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Kakuhen,
+                result: LOSE,
+            },
+            GameEvent::KakuhenEnded { n: 2 },
+        ]
+    );
+}
+
+#[test]
+fn running_out_of_balls_finishes_the_game() {
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 2,
+            hold_capacity: 1,
+            spin_duration: 5,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: NEVER,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, EventLog::default()).unwrap();
+    game.start().unwrap();
+    game.enter_start_hole().unwrap();
+    game.enter_start_hole().unwrap();
+    game.enter_start_hole().unwrap();
+    game.launch_ball().unwrap();
+    game.launch_ball().unwrap();
+
+    let spinning = Hold {
+        pending: 1,
+        spin_remaining: 5,
+        wasted: 0,
+    };
+    assert_eq!(
+        game.output().events,
+        [
+            GameEvent::GameStarted { balls: 2 },
+            GameEvent::StartHoleEntered {
+                hold: Hold::default()
+            },
+            GameEvent::LotteryDrawn {
+                mode: LotteryMode::Normal,
+                result: LotteryResult::Lose(Lose::Default),
+            },
+            GameEvent::StartHoleEntered { hold: spinning },
+            GameEvent::StartHoleEntered {
+                hold: Hold {
+                    wasted: 1,
+                    ..spinning
+                }
+            },
+            GameEvent::BallLaunched { balls: 1 },
+            GameEvent::BallLaunched { balls: 0 },
+            GameEvent::GameFinished { balls: 0 },
+        ]
+    );
+}
@@ -0,0 +1,466 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{
+        BallsConfig, Ceiling, CeilingReward, Config, Payout, Probability, RoundWeight, RushModel,
+    },
+    game::{Bonus, Game, GameState, Hold},
+};
+
+mod common;
+
+use common::{ALWAYS, LOSE, NEVER, NullInput, Record, RecordingOutput, WIN};
+
+fn overshooting_curve(n: usize) -> f64 {
+    if n == 1 { 1.0 } else { 2.0 }
+}
+
+#[test]
+fn probability_error_is_reported_to_output() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            normal: ALWAYS,
+            rush: ALWAYS,
+            rush_continue: ALWAYS,
+            rush_continue_fn: overshooting_curve as fn(usize) -> f64,
+            rush_continue_horizon: 1,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    // Normal win enters rush, the first continuation uses f(1) == 1.0
+    game.cause_lottery();
+    game.cause_lottery();
+    // The second continuation would use 2.0, which is not a probability
+    game.cause_lottery();
+
+    assert_eq!(
+        game.output().records,
+        [
+            Record::Normal(WIN),
+            Record::Rush(WIN),
+            Record::RushContinue(WIN),
+            Record::Rush(WIN),
+            Record::ProbabilityError,
+        ]
+    );
+
+    let balls = CONFIG_EXAMPLE.balls;
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: balls.init_balls + 3 * balls.incremental_balls,
+            rush_balls: 2 * balls.incremental_rush,
+            n: 2,
+        }
+    );
+}
+
+#[test]
+fn entries_wait_in_hold_while_reels_spin() {
+    let config = Config {
+        balls: BallsConfig {
+            hold_capacity: 2,
+            spin_duration: 3,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: NEVER,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    // The first entry starts a spin, the next two are held and the last one overflows
+    for _ in 0..4 {
+        game.enter_start_hole().unwrap();
+    }
+    assert_eq!(
+        *game.hold(),
+        Hold {
+            pending: 2,
+            spin_remaining: 3,
+            wasted: 1,
+        }
+    );
+    assert_eq!(game.output().records.len(), 1);
+
+    game.launch_ball().unwrap();
+    game.launch_ball().unwrap();
+    assert_eq!(game.output().records.len(), 1);
+
+    // The spin stops and the first held entry is drawn
+    game.launch_ball().unwrap();
+    assert_eq!(game.output().records, [Record::Normal(LOSE); 2]);
+    assert_eq!(
+        *game.hold(),
+        Hold {
+            pending: 1,
+            spin_remaining: 3,
+            wasted: 1,
+        }
+    );
+}
+
+#[test]
+fn kakuhen_jackpot_routes_into_kakuhen_again() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            normal: ALWAYS,
+            kakuhen: ALWAYS,
+            kakuhen_entry: 1.0,
+            kakuhen_fall: 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    game.cause_lottery();
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Kakuhen {
+            balls: balls.init_balls + 2 * balls.incremental_balls,
+            n: 2,
+        }
+    );
+    assert_eq!(
+        game.output().records,
+        [Record::Normal(WIN), Record::Kakuhen(WIN)]
+    );
+}
+
+#[test]
+fn kakuhen_falls_back_to_normal() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            normal: ALWAYS,
+            kakuhen: NEVER,
+            kakuhen_entry: 1.0,
+            kakuhen_fall: 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    game.cause_lottery();
+    assert!(matches!(game.state(), GameState::Kakuhen { n: 1, .. }));
+
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: balls.init_balls + balls.incremental_balls,
+            spins: 0,
+        }
+    );
+}
+
+#[test]
+fn rush_ends_in_jitan() {
+    let config = Config {
+        balls: BallsConfig {
+            incremental_rush: 2,
+            jitan_spins: 5,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: ALWAYS,
+            jitan_start_hole: 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+    assert!(!game.assist_start_hole());
+
+    game.cause_lottery();
+    game.launch_ball().unwrap();
+    game.launch_ball().unwrap();
+    assert_eq!(
+        *game.state(),
+        GameState::Jitan {
+            balls: balls.init_balls + balls.incremental_balls,
+            remaining_spins: 5,
+        }
+    );
+    assert!(game.assist_start_hole());
+
+    // Jitan has the jackpot odds of normal mode
+    game.cause_lottery();
+    assert!(matches!(game.state(), GameState::Rush { n: 1, .. }));
+    assert_eq!(game.output().records, [Record::Normal(WIN); 2]);
+}
+
+#[test]
+fn special_times_rush_is_limited_by_spins() {
+    let special_times = |rush| Config {
+        balls: BallsConfig {
+            rush_model: RushModel::SpecialTimes { spins: 3 },
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: ALWAYS,
+            rush,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = CONFIG_EXAMPLE.balls;
+
+    // A jackpot within the spins continues the rush with fresh spins
+    let mut game: Game<_, _> =
+        Game::new(special_times(ALWAYS), NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+    game.cause_lottery();
+    game.launch_ball().unwrap();
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::SpecialTimes {
+            balls: balls.init_balls + 2 * balls.incremental_balls,
+            remaining_spins: 3,
+            n: 2,
+        }
+    );
+
+    // Without a jackpot the rush ends when the spins run out
+    let mut game: Game<_, _> =
+        Game::new(special_times(NEVER), NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+    game.cause_lottery();
+    game.cause_lottery();
+    game.cause_lottery();
+    assert!(matches!(
+        game.state(),
+        GameState::SpecialTimes {
+            remaining_spins: 1,
+            n: 1,
+            ..
+        }
+    ));
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: balls.init_balls + balls.incremental_balls,
+            spins: 0,
+        }
+    );
+    assert_eq!(
+        game.output().records,
+        [
+            Record::Normal(WIN),
+            Record::Rush(LOSE),
+            Record::Rush(LOSE),
+            Record::Rush(LOSE)
+        ]
+    );
+}
+
+#[test]
+fn jackpot_is_paid_out_in_rounds() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            normal: ALWAYS,
+            rush: NEVER,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: Some(Payout {
+            normal: vec![
+                RoundWeight {
+                    rounds: 2,
+                    weight: 1,
+                },
+                RoundWeight {
+                    rounds: 16,
+                    weight: 0,
+                },
+            ],
+            rush: vec![RoundWeight {
+                rounds: 10,
+                weight: 1,
+            }],
+            counts: 3,
+            balls_per_count: 10,
+        }),
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    // The jackpot enters rush, but pays nothing until the attacker is fed
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: balls.init_balls,
+            rush_balls: balls.incremental_rush,
+            n: 1,
+        }
+    );
+    let first_round = Bonus {
+        rounds: 2,
+        round: 1,
+        count: 3,
+    };
+    assert_eq!(
+        game.bonus(),
+        Some(&Bonus {
+            count: 0,
+            ..first_round
+        })
+    );
+
+    // No spin is drawn during the bonus, entries are held
+    game.cause_lottery();
+    game.enter_start_hole().unwrap();
+    game.enter_start_hole().unwrap();
+    assert_eq!(game.hold().pending, 2);
+
+    for _ in 0..6 {
+        game.launch_ball().unwrap();
+    }
+    assert_eq!(game.bonus(), None);
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: balls.init_balls + 6 * 9,
+            rush_balls: balls.incremental_rush,
+            n: 1,
+        }
+    );
+
+    // The held entries are drawn once the bonus is over
+    assert_eq!(game.hold().pending, 0);
+    assert_eq!(
+        game.output().records,
+        [
+            Record::Normal(WIN),
+            Record::BonusRound(first_round),
+            Record::BonusRound(Bonus {
+                round: 2,
+                ..first_round
+            }),
+            Record::Rush(LOSE),
+            Record::Rush(LOSE),
+        ]
+    );
+}
+
+#[test]
+fn ceiling_forces_jitan_after_losing_streak() {
+    const CEILING: Ceiling = Ceiling {
+        spins: 3,
+        reward: CeilingReward::Jitan { spins: 10 },
+    };
+
+    let config = Config {
+        balls: BallsConfig {
+            ceiling: Some(CEILING),
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: NEVER,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    game.cause_lottery();
+    game.launch_ball().unwrap();
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: balls.init_balls - 1,
+            spins: 2,
+        }
+    );
+
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Jitan {
+            balls: balls.init_balls - 1,
+            remaining_spins: 10,
+        }
+    );
+    assert_eq!(
+        game.output().records,
+        [
+            Record::Normal(LOSE),
+            Record::Normal(LOSE),
+            Record::Normal(LOSE),
+            Record::Ceiling(CEILING),
+        ]
+    );
+
+    // The streak starts over once jitan is used up
+    for _ in 0..10 {
+        game.cause_lottery();
+    }
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: balls.init_balls - 1,
+            spins: 0,
+        }
+    );
+}
+
+#[test]
+fn jackpot_missing_rush_continues_in_jitan() {
+    let config = Config {
+        balls: BallsConfig {
+            jitan_spins: 5,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: ALWAYS,
+            rush_entry: 0.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let balls = config.balls;
+
+    let mut game: Game<_, _> = Game::new(config, NullInput, RecordingOutput::default()).unwrap();
+    game.start().unwrap();
+
+    // The jackpot is paid out without entering rush
+    game.cause_lottery();
+    assert_eq!(
+        *game.state(),
+        GameState::Jitan {
+            balls: balls.init_balls + balls.incremental_balls,
+            remaining_spins: 5,
+        }
+    );
+}
@@ -1,167 +1,71 @@
-use std::{
-    sync::{Arc, Mutex},
-    thread,
-};
-
 use pachislo::{
     CONFIG_EXAMPLE as CONFIG, START_HOLE_PROBABILITY_EXAMPLE,
-    command::{Command, LaunchBallFlowProducer, StartGame},
-    game::{Game, GameState, Transition},
-    interface::{UserInput, UserOutput},
-    lottery::LotteryResult,
+    analysis::analyze,
+    simulate::{Simulation, SimulationReport},
 };
 
-struct TestInput {
-    n: usize,
-    launch_ball_flow_producer: LaunchBallFlowProducer,
-    first: bool,
-}
-
-impl TestInput {
-    fn new(n: usize, start_hole_probability: f64) -> Self {
-        assert!((0.0..=1.0).contains(&start_hole_probability));
-        TestInput {
-            n,
-            launch_ball_flow_producer: LaunchBallFlowProducer::new(start_hole_probability),
-            first: true,
-        }
+/// Checks the invariants of a report and its payout ratio against the analysis.
+fn check(simulation: &Simulation, report: &SimulationReport, tolerance: f64) {
+    assert_eq!(report.sessions, simulation.sessions);
+    assert!(report.launches <= simulation.launches * simulation.sessions);
+    assert!(report.paid_launches <= report.launches);
+    for rate in [
+        report.normal,
+        report.rush,
+        report.rush_continue,
+        report.kakuhen,
+    ] {
+        assert!(rate.hits <= rate.spins);
     }
+    let spins = report.normal.spins + report.rush.spins + report.kakuhen.spins;
+    assert!(spins <= report.launches);
+
+    assert_eq!(
+        report.rush_count(),
+        report.rush_lengths.iter().sum::<usize>()
+    );
+    assert!(report.rush_count() <= report.normal.hits + report.kakuhen.hits);
+    assert_eq!(report.rush_lengths.len(), report.max_chain);
+
+    let expected = analyze(CONFIG, simulation.start_hole_probability)
+        .unwrap()
+        .payout_rate;
+    let ratio = report.payout_ratio();
+    assert!(
+        (ratio - expected).abs() <= tolerance * expected,
+        "payout ratio {ratio} is not within {tolerance} of {expected}"
+    );
 }
 
-impl UserInput<TestOutput> for TestInput {
-    fn wait_for_input(&mut self) -> Command<Self, TestOutput> {
-        if self.first {
-            self.first = false;
-            Command::Control(Box::new(StartGame))
-        } else if self.n > 0 {
-            self.n -= 1;
-
-            Command::Control(Box::new(
-                self.launch_ball_flow_producer.produce(),
-            ))
-        } else {
-            Command::FinishGame
-        }
-    }
-}
-
-struct TestOutput {
-    win_normal: usize,
-    win_rush: usize,
-    win_rush_continue: usize,
-    max_continue: usize,
-    continue_count: usize,
-    continue_sum: usize,
-    final_state: Vec<GameState>,
-}
-
-impl UserOutput for TestOutput {
-    fn default(&mut self, state: Transition) {
-        let Transition {
-            before,
-            after: state,
-        } = state;
-
-        if let (GameState::Normal { .. }, Some(GameState::Rush { n, .. })) = (state, before) {
-            self.continue_count += 1;
-            self.continue_sum += n;
-            if n > self.max_continue {
-                self.max_continue = n;
-            }
-        }
-    }
-
-    fn finish_game(&mut self, state: &GameState) {
-        self.final_state[0] = *state;
-    }
-
-    fn lottery_normal(&mut self, result: LotteryResult) {
-        if result.is_win() {
-            self.win_normal += 1;
-        }
-    }
-
-    fn lottery_rush(&mut self, result: LotteryResult) {
-        if result.is_win() {
-            self.win_rush += 1;
-        }
-    }
-
-    fn lottery_rush_continue(&mut self, result: LotteryResult) {
-        if result.is_win() {
-            self.win_rush_continue += 1;
-        }
-    }
-}
+#[test]
+fn simulation_is_consistent() {
+    let simulation = Simulation {
+        start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+        launches: 200_000,
+        sessions: 8,
+        seed: Some(42),
+    };
 
-impl TestOutput {
-    fn new() -> Self {
-        Self {
-            win_normal: 0,
-            win_rush: 0,
-            win_rush_continue: 0,
-            max_continue: 0,
-            continue_count: 0,
-            continue_sum: 0,
-            final_state: vec![GameState::Uninitialized],
-        }
-    }
+    let report = simulation.run(&CONFIG).unwrap();
 
-    fn add(&mut self, other: &TestOutput) {
-        self.win_normal += other.win_normal;
-        self.win_rush += other.win_rush;
-        self.win_rush_continue += other.win_rush_continue;
-        self.max_continue = self.max_continue.max(other.max_continue);
-        self.continue_count += other.continue_count;
-        self.continue_sum += other.continue_sum;
-        self.final_state.push(other.final_state[0]);
-    }
+    check(&simulation, &report, 0.1);
 }
 
 #[test]
-fn test() {
-    thread::scope(|s| {
-        let mut handles = Vec::with_capacity(8);
-
-        let global_output = Arc::new(Mutex::new(TestOutput::new()));
-
-        for _ in 0..8 {
-            let global_output = Arc::clone(&global_output);
-
-            let handle = s.spawn(move || {
-                let input = TestInput::new(20000000, START_HOLE_PROBABILITY_EXAMPLE);
-
-                let output = TestOutput::new();
-
-                let mut game = Game::new(CONFIG, input, output).unwrap();
-
-                game.run();
-
-                global_output.lock().unwrap().add(game.output());
-            });
-
-            handles.push(handle);
-        }
-
-        for handle in handles {
-            handle.join().unwrap();
-        }
-
-        let output = global_output.lock().unwrap();
-
-        println!("Win normal: {}", output.win_normal);
-        println!("Win rush: {}", output.win_rush);
-        println!("Win rush continue: {}", output.win_rush_continue);
-        println!(
-            "Total: {}",
-            output.win_normal + output.win_rush + output.win_rush_continue
-        );
-        println!("Continue count: {}", output.continue_count);
-        println!(
-            "Average continue: {}",
-            output.continue_sum as f64 / output.continue_count as f64
-        );
-        println!("Max continue: {}", output.max_continue);
-        println!("Final state: {:#?}", output.final_state);
-    });
+#[ignore = "long run, use `cargo test --release -- --ignored`"]
+fn long_run() {
+    let simulation = Simulation {
+        start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+        launches: 20_000_000,
+        sessions: 8,
+        seed: Some(42),
+    };
+
+    let report = simulation.run(&CONFIG).unwrap();
+
+    check(&simulation, &report, 0.02);
+    println!("Continue count: {}", report.rush_count());
+    println!("Average continue: {}", report.average_rush_length());
+    println!("Max continue: {}", report.max_chain);
+    println!("Payout ratio: {}", report.payout_ratio());
 }
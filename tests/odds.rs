@@ -0,0 +1,59 @@
+use pachislo::{config::SlotProbability, odds::Odds, simulate::HitRate};
+
+#[test]
+fn parses_every_notation() {
+    let parse = |s: &str| s.parse::<Odds>().unwrap().0;
+
+    assert_eq!(parse("1/319.7"), 1.0 / 319.7);
+    assert_eq!(parse(" 1 / 99 "), 1.0 / 99.0);
+    assert_eq!(parse("2/5"), 0.4);
+    assert_eq!(parse("0.5%"), 0.005);
+    assert_eq!(parse("100%"), 1.0);
+    assert_eq!(parse("0.25"), 0.25);
+    assert_eq!(parse("0"), 0.0);
+}
+
+#[test]
+fn rejects_invalid_odds() {
+    for input in [
+        "",
+        "1/",
+        "1/0",
+        "1/-5",
+        "one in ten",
+        "150%",
+        "1/0.5",
+        "-0.1",
+        "NaN",
+    ] {
+        assert!(input.parse::<Odds>().is_err(), "{input:?} was accepted");
+    }
+
+    let error = "1/0".parse::<Odds>().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        r#"invalid odds "1/0": denominator must be greater than 0"#
+    );
+}
+
+#[test]
+fn displays_both_forms() {
+    assert_eq!(Odds(1.0 / 319.7).to_string(), "1/319.7 (0.31%)");
+    assert_eq!(Odds(0.5).to_string(), "1/2.0 (50.00%)");
+    assert_eq!(Odds(0.0).to_string(), "never (0.00%)");
+
+    let normal = SlotProbability {
+        fake_win: 0.3,
+        ..SlotProbability::from_odds("1/99").unwrap()
+    };
+    assert_eq!(
+        normal.to_string(),
+        "win 1/99.0 (1.01%), fake win 30.00%, fake lose 0.00%"
+    );
+
+    let hits = HitRate {
+        spins: 400,
+        hits: 4,
+    };
+    assert_eq!(hits.to_string(), "4/400 hits, 1/100.0 (1.00%)");
+}
@@ -0,0 +1,56 @@
+use pachislo::{
+    analysis::analyze,
+    config::RushModel,
+    presets::{self, Preset},
+    simulate::Simulation,
+};
+
+fn continuation(preset: &Preset) -> f64 {
+    let RushModel::SpecialTimes { spins } = preset.config.balls.rush_model else {
+        panic!("{} is not an ST machine", preset.name);
+    };
+    1.0 - (1.0 - preset.config.probability.rush.win).powi(spins as i32)
+}
+
+#[test]
+fn metadata_matches_config() {
+    let presets = presets::all();
+    assert_eq!(presets.len(), 4);
+
+    for preset in presets {
+        let probability = &preset.config.probability;
+        assert!((probability.normal.win * preset.nominal_odds - 1.0).abs() < 1e-12);
+        assert_eq!(probability.rush_entry, preset.rush_entry);
+        assert!(
+            (continuation(&preset) - preset.continuation).abs() < 0.01,
+            "{}: continuation {}",
+            preset.name,
+            continuation(&preset)
+        );
+    }
+}
+
+#[test]
+fn presets_are_ordered_by_odds() {
+    let odds: Vec<f64> = presets::all().iter().map(|p| p.nominal_odds).collect();
+    assert!(odds.windows(2).all(|w| w[0] < w[1]));
+}
+
+#[test]
+fn presets_can_be_analysed_and_simulated() {
+    for preset in presets::all() {
+        let analysis = analyze(preset.config.clone(), preset.start_hole_probability)
+            .unwrap_or_else(|error| panic!("{}: {error}", preset.name));
+        assert!(analysis.payout_rate > 0.5 && analysis.payout_rate < 1.5);
+
+        let report = Simulation {
+            start_hole_probability: preset.start_hole_probability,
+            launches: 1_000,
+            sessions: 2,
+            seed: Some(1),
+        }
+        .run(&preset.config)
+        .unwrap();
+        assert_eq!(report.sessions, 2);
+    }
+}
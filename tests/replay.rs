@@ -0,0 +1,322 @@
+use pachislo::{
+    CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE,
+    command::{Command, ControlCommand, LaunchBallFlowProducer, RecordedCommand, StartGame},
+    game::{Game, GameBuilder, GameError, GameState, Hold, Transition},
+    interface::{UserInput, UserOutput},
+    lottery::LotteryResult,
+    replay::{LogEntry, Recorder, ReplayError, Replayer, SessionLog},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::{Record, RecordingOutput};
+
+/// Starts a game, launches `launches` balls and ends the game loop.
+struct ScriptedInput {
+    producer: LaunchBallFlowProducer<StdRng>,
+    launches: usize,
+    started: bool,
+}
+
+impl ScriptedInput {
+    fn new(launches: usize) -> Self {
+        Self {
+            producer: LaunchBallFlowProducer::from_seed(START_HOLE_PROBABILITY_EXAMPLE, 7),
+            launches,
+            started: false,
+        }
+    }
+}
+
+impl<O: UserOutput> UserInput<O, fn(usize) -> f64, StdRng> for ScriptedInput {
+    fn wait_for_input(&mut self) -> Command<Self, O, fn(usize) -> f64, StdRng> {
+        if !self.started {
+            self.started = true;
+            Command::control(StartGame)
+        } else if self.launches > 0 {
+            self.launches -= 1;
+            Command::control(self.producer.produce())
+        } else {
+            Command::FinishGame
+        }
+    }
+}
+
+/// Output collecting the transitions passed to `UserOutput::default`.
+struct TransitionLog {
+    transitions: Vec<Transition>,
+}
+
+impl UserOutput for TransitionLog {
+    fn default(&mut self, state: Transition) {
+        self.transitions.push(state);
+    }
+
+    fn finish_game(&mut self, _state: &GameState) {}
+
+    fn lottery_normal(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush(&mut self, _result: LotteryResult) {}
+
+    fn lottery_rush_continue(&mut self, _result: LotteryResult) {}
+}
+
+/// Custom command without a recorded form.
+struct Noop;
+
+impl<I, O> ControlCommand<I, O, fn(usize) -> f64, StdRng> for Noop
+where
+    I: UserInput<O, fn(usize) -> f64, StdRng>,
+    O: UserOutput,
+{
+    fn execute(
+        &mut self,
+        _game: &mut Game<I, O, fn(usize) -> f64, StdRng>,
+    ) -> Result<(), GameError> {
+        Ok(())
+    }
+}
+
+/// Starts a game, then returns a single custom command.
+struct CustomInput {
+    commands: usize,
+}
+
+impl<O: UserOutput> UserInput<O, fn(usize) -> f64, StdRng> for CustomInput {
+    fn wait_for_input(&mut self) -> Command<Self, O, fn(usize) -> f64, StdRng> {
+        self.commands += 1;
+        match self.commands {
+            1 => Command::control(StartGame),
+            2 => Command::control(Noop),
+            _ => Command::FinishGame,
+        }
+    }
+}
+
+fn record(launches: usize, seed: u64) -> (SessionLog, Vec<Record>) {
+    let recorder = Recorder::new();
+    let output = recorder.wrap(RecordingOutput {
+        records: Vec::new(),
+    });
+    let mut game: Game<_, _, _, StdRng> =
+        Game::from_seed(CONFIG_EXAMPLE, ScriptedInput::new(launches), output, seed).unwrap();
+    game.run();
+
+    (recorder.log(), game.output().inner().records.clone())
+}
+
+#[test]
+fn recorder_passes_the_session_through() {
+    let (log, records) = record(500, 42);
+
+    assert_eq!(log.commands().next(), Some(RecordedCommand::StartGame));
+    assert_eq!(log.commands().count(), 502);
+    // Every command is followed by the transition reported with it
+    assert!(matches!(
+        log.entries[log.entries.len() - 2..],
+        [
+            LogEntry::Command(RecordedCommand::Quit),
+            LogEntry::Transition(_)
+        ]
+    ));
+    let transitions = log
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry, LogEntry::Transition(_)))
+        .count();
+    assert_eq!(transitions, 502);
+
+    let lotteries = log
+        .entries
+        .iter()
+        .filter(|entry| matches!(entry, LogEntry::Lottery { .. }))
+        .count();
+    assert!(lotteries > 0);
+    assert_eq!(lotteries, records.len());
+}
+
+#[test]
+fn replay_reproduces_the_session() {
+    let (log, _) = record(2_000, 42);
+
+    assert_eq!(
+        Replayer::verify(&log, CONFIG_EXAMPLE, StdRng::seed_from_u64(42)),
+        Ok(())
+    );
+
+    // A session cut off before a command replays up to the cut
+    let cut = log
+        .entries
+        .iter()
+        .rposition(|entry| {
+            *entry == LogEntry::Command(RecordedCommand::LaunchBallFlow { is_lottery: true })
+        })
+        .unwrap();
+    let partial = SessionLog {
+        start: None,
+        entries: log.entries[..cut].to_vec(),
+    };
+    assert_eq!(
+        Replayer::verify(&partial, CONFIG_EXAMPLE, StdRng::seed_from_u64(42)),
+        Ok(())
+    );
+}
+
+#[test]
+fn replay_recomputes_the_transitions() {
+    let recorder = Recorder::new();
+    let output = recorder.wrap(TransitionLog {
+        transitions: Vec::new(),
+    });
+    let mut game: Game<_, _, _, StdRng> =
+        Game::from_seed(CONFIG_EXAMPLE, ScriptedInput::new(1_000), output, 42).unwrap();
+    game.run();
+    let log = recorder.log();
+
+    let replayed = Replayer::replay(
+        &log,
+        CONFIG_EXAMPLE,
+        StdRng::seed_from_u64(42),
+        TransitionLog {
+            transitions: Vec::new(),
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        replayed.output().transitions,
+        game.output().inner().transitions
+    );
+    assert_eq!(replayed.state(), game.state());
+}
+
+#[test]
+fn replay_starts_from_the_recorded_position() {
+    let rush = GameState::Rush {
+        balls: 500,
+        rush_balls: 40,
+        n: 7,
+    };
+    let recorder = Recorder::new();
+    let output = recorder.wrap(TransitionLog {
+        transitions: Vec::new(),
+    });
+    let mut game = GameBuilder::with_rng(
+        CONFIG_EXAMPLE,
+        ScriptedInput::new(1_000),
+        output,
+        StdRng::seed_from_u64(42),
+    )
+    .state(rush)
+    .build()
+    .unwrap();
+    recorder.start_from(&game.snapshot());
+    game.run();
+    let log = recorder.log();
+
+    assert_eq!(log.start.map(|start| start.state), Some(rush));
+    assert!(log.entries.contains(&LogEntry::Transition(Transition {
+        before: None,
+        after: rush,
+        hold: Hold::default(),
+        bonus: None,
+    })));
+    assert_eq!(
+        Replayer::verify(&log, CONFIG_EXAMPLE, StdRng::seed_from_u64(42)),
+        Ok(())
+    );
+
+    // Without its starting position the replay starts fresh and diverges
+    let fresh = SessionLog {
+        start: None,
+        ..log.clone()
+    };
+    let Err(ReplayError::Divergence(divergence)) =
+        Replayer::verify(&fresh, CONFIG_EXAMPLE, StdRng::seed_from_u64(42))
+    else {
+        panic!("a fresh game replayed a session started in rush");
+    };
+    assert!(matches!(divergence.expected, Some(LogEntry::Transition(_))));
+}
+
+#[test]
+fn custom_commands_are_logged_as_unrecordable() {
+    let recorder = Recorder::new();
+    let output = recorder.wrap(RecordingOutput {
+        records: Vec::new(),
+    });
+    let mut game: Game<_, _, _, StdRng> =
+        Game::from_seed(CONFIG_EXAMPLE, CustomInput { commands: 0 }, output, 42).unwrap();
+    game.run();
+    let log = recorder.log();
+
+    // The custom command still reached the game
+    const NORMAL: GameState = GameState::Normal {
+        balls: 1000,
+        spins: 0,
+    };
+    assert_eq!(*game.state(), NORMAL);
+    assert_eq!(
+        log.entries,
+        [
+            LogEntry::Command(RecordedCommand::StartGame),
+            LogEntry::Transition(Transition {
+                before: None,
+                after: GameState::Uninitialized,
+                hold: Hold::default(),
+                bonus: None,
+            }),
+            LogEntry::Unrecordable,
+            LogEntry::Transition(Transition {
+                before: Some(GameState::Uninitialized),
+                after: NORMAL,
+                hold: Hold::default(),
+                bonus: None,
+            }),
+            LogEntry::Command(RecordedCommand::Quit),
+            LogEntry::Transition(Transition {
+                before: Some(NORMAL),
+                after: NORMAL,
+                hold: Hold::default(),
+                bonus: None,
+            }),
+        ]
+    );
+    assert_eq!(
+        Replayer::verify(&log, CONFIG_EXAMPLE, StdRng::seed_from_u64(42)),
+        Err(ReplayError::Unrecordable { index: 2 })
+    );
+}
+
+#[test]
+fn replay_reports_the_first_divergence() {
+    let (log, _) = record(2_000, 42);
+
+    let Err(ReplayError::Divergence(divergence)) =
+        Replayer::verify(&log, CONFIG_EXAMPLE, StdRng::seed_from_u64(43))
+    else {
+        panic!("a different seed replayed the same session");
+    };
+
+    assert_ne!(divergence.expected, divergence.actual);
+    assert!(matches!(
+        divergence.expected,
+        Some(LogEntry::Lottery { .. })
+    ));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn log_survives_serialization() {
+    let (log, _) = record(200, 42);
+
+    let json = serde_json::to_string(&log).unwrap();
+    let reloaded: SessionLog = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(reloaded, log);
+    assert_eq!(
+        Replayer::verify(&reloaded, CONFIG_EXAMPLE, StdRng::seed_from_u64(42)),
+        Ok(())
+    );
+}
@@ -0,0 +1,141 @@
+use pachislo::{
+    CONFIG_EXAMPLE as CONFIG, START_HOLE_PROBABILITY_EXAMPLE,
+    command::{Command, LaunchBallFlowProducer, StartGame},
+    game::{Game, Transition},
+    interface::{UserInput, UserOutput},
+    lottery::{Lottery, LotteryResult},
+    seed::SessionRngs,
+    slot::SlotProducer,
+};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+struct SeededInput {
+    n: usize,
+    launch_ball_flow_producer: LaunchBallFlowProducer<StdRng>,
+    first: bool,
+}
+
+impl UserInput<RecordingOutput, fn(usize) -> f64, StdRng> for SeededInput {
+    fn wait_for_input(&mut self) -> Command<Self, RecordingOutput, fn(usize) -> f64, StdRng> {
+        if self.first {
+            self.first = false;
+            Command::control(StartGame)
+        } else if self.n > 0 {
+            self.n -= 1;
+            Command::control(self.launch_ball_flow_producer.produce())
+        } else {
+            Command::FinishGame
+        }
+    }
+}
+
+type Session = (Vec<Transition>, Vec<(LotteryResult, Slot)>);
+
+type Slot = (Vec<u8>, Option<Vec<u8>>);
+
+struct RecordingOutput {
+    slot_producer: SlotProducer<u8, StdRng>,
+    transitions: Vec<Transition>,
+    lotteries: Vec<(LotteryResult, Slot)>,
+}
+
+impl RecordingOutput {
+    fn record(&mut self, result: LotteryResult) {
+        let slot = self.slot_producer.produce(&result);
+        self.lotteries.push((result, slot));
+    }
+}
+
+impl UserOutput for RecordingOutput {
+    fn default(&mut self, state: Transition) {
+        self.transitions.push(state);
+    }
+
+    fn finish_game(&mut self, _state: &pachislo::game::GameState) {}
+
+    fn lottery_normal(&mut self, result: LotteryResult) {
+        self.record(result);
+    }
+
+    fn lottery_rush(&mut self, result: LotteryResult) {
+        self.record(result);
+    }
+
+    fn lottery_rush_continue(&mut self, result: LotteryResult) {
+        self.record(result);
+    }
+}
+
+/// Runs a whole session where every random decision derives from `seed`.
+fn run_session(seed: u64) -> Session {
+    let rngs = SessionRngs::from_seed(seed);
+
+    let input = SeededInput {
+        n: 100_000,
+        launch_ball_flow_producer: LaunchBallFlowProducer::with_rng(
+            START_HOLE_PROBABILITY_EXAMPLE,
+            rngs.start_hole,
+        ),
+        first: true,
+    };
+    let output = RecordingOutput {
+        slot_producer: SlotProducer::with_rng(3, (1..=9).collect(), rngs.slot),
+        transitions: Vec::new(),
+        lotteries: Vec::new(),
+    };
+
+    let mut game = Game::with_rng(CONFIG, input, output, rngs.lottery).unwrap();
+    game.run();
+
+    let output = game.output();
+    (output.transitions.clone(), output.lotteries.clone())
+}
+
+#[test]
+fn same_seed_replays_session() {
+    let first = run_session(40);
+    let second = run_session(40);
+
+    assert!(!first.1.is_empty());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn different_seed_diverges() {
+    let first = run_session(40);
+    let second = run_session(41);
+
+    assert_ne!(first.1, second.1);
+}
+
+#[test]
+fn session_rngs_are_independent() {
+    let SessionRngs {
+        mut start_hole,
+        mut lottery,
+        mut slot,
+    } = SessionRngs::from_seed(7);
+    let draws = |rng: &mut StdRng| -> Vec<u64> { (0..8).map(|_| rng.random()).collect() };
+
+    let start_hole = draws(&mut start_hole);
+    let lottery = draws(&mut lottery);
+    let slot = draws(&mut slot);
+    assert_ne!(start_hole, lottery);
+    assert_ne!(lottery, slot);
+    assert_ne!(start_hole, slot);
+
+    assert_eq!(draws(&mut SessionRngs::from_seed(7).lottery), lottery);
+}
+
+#[test]
+fn certain_decisions_leave_the_stream_untouched() {
+    let mut lottery = Lottery::with_rng(CONFIG.probability, StdRng::seed_from_u64(3));
+    let mut reference = Lottery::with_rng(CONFIG.probability, StdRng::seed_from_u64(3));
+
+    for _ in 0..100 {
+        // CONFIG never routes into kakuhen and always enters rush
+        assert!(!lottery.lottery_kakuhen_entry());
+        assert!(lottery.lottery_rush_entry());
+        assert_eq!(lottery.lottery_normal(), reference.lottery_normal());
+    }
+}
@@ -0,0 +1,144 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{Config, Probability},
+    event::{GameEvent, LotteryMode},
+    game::{Bonus, Game, GameState},
+    lottery::{JackpotCategory, ScriptedLottery},
+    presets,
+};
+
+mod common;
+
+use common::{EventLog, LOSE, NullInput, WIN};
+
+#[test]
+fn rush_follows_the_script() {
+    // Jackpot, continue, continue, fail, then lose the rest of the rush
+    let script = ScriptedLottery::new()
+        .normal([WIN, LOSE])
+        .rush([WIN, WIN, WIN])
+        .rush([LOSE; 899])
+        .rush_continue([WIN, WIN, LOSE]);
+    let balls = CONFIG_EXAMPLE.balls;
+    let mut game = Game::builder(CONFIG_EXAMPLE, NullInput, EventLog { events: Vec::new() })
+        .rng(script)
+        .build()
+        .unwrap();
+
+    for _ in 0..4 {
+        game.enter_start_hole().unwrap();
+    }
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: balls.init_balls + 4 * balls.incremental_balls,
+            rush_balls: 3 * balls.incremental_rush,
+            n: 3,
+        }
+    );
+
+    // The rush balls run out after 900 launches
+    while matches!(game.state(), GameState::Rush { .. }) {
+        game.launch_ball().unwrap();
+        game.enter_start_hole().unwrap();
+    }
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: balls.init_balls + 4 * balls.incremental_balls,
+            spins: 1,
+        }
+    );
+    assert!(game.output().events.contains(&GameEvent::RushEnded {
+        n: 3,
+        gained: 4 * balls.incremental_balls,
+    }));
+    assert!(game.snapshot().rng.is_exhausted());
+}
+
+#[test]
+#[should_panic(expected = "Rush lottery script exhausted")]
+fn exhausted_script_panics() {
+    let script = ScriptedLottery::new().normal([WIN]);
+    let mut game = Game::builder(CONFIG_EXAMPLE, NullInput, EventLog { events: Vec::new() })
+        .rng(script)
+        .build()
+        .unwrap();
+
+    game.enter_start_hole().unwrap();
+    assert_eq!(game.snapshot().rng.remaining(LotteryMode::Normal), 0);
+    game.enter_start_hole().unwrap();
+}
+
+#[test]
+fn uncertain_decisions_follow_the_script() {
+    let preset = presets::middle();
+    let script = ScriptedLottery::new()
+        .normal([WIN])
+        .categories([JackpotCategory::Normal])
+        .rounds([10]);
+    let mut game = Game::builder(preset.config, NullInput, EventLog { events: Vec::new() })
+        .rng(script)
+        .build()
+        .unwrap();
+
+    // The rush entry of 50% is missed and the second round table entry is paid
+    game.enter_start_hole().unwrap();
+    assert!(game.snapshot().rng.is_exhausted());
+    assert_eq!(
+        *game.state(),
+        GameState::Normal {
+            balls: 1000,
+            spins: 0
+        }
+    );
+    assert_eq!(
+        game.bonus(),
+        Some(&Bonus {
+            rounds: 10,
+            round: 1,
+            count: 0
+        })
+    );
+}
+
+#[test]
+#[should_panic(expected = "Jackpot category script exhausted")]
+fn uncertain_decisions_without_script_panic() {
+    let preset = presets::middle();
+    let mut game = Game::builder(preset.config, NullInput, EventLog { events: Vec::new() })
+        .rng(ScriptedLottery::new().normal([WIN]))
+        .build()
+        .unwrap();
+
+    game.enter_start_hole().unwrap();
+}
+
+#[test]
+fn kakuhen_fall_follows_the_decision_script() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            kakuhen_entry: 1.0,
+            kakuhen_fall: 0.5,
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let script = ScriptedLottery::new()
+        .normal([WIN])
+        .kakuhen([LOSE, LOSE])
+        .decisions([false, true]);
+    let mut game = Game::builder(config, NullInput, EventLog { events: Vec::new() })
+        .rng(script)
+        .build()
+        .unwrap();
+
+    game.enter_start_hole().unwrap();
+    game.enter_start_hole().unwrap();
+    assert!(matches!(game.state(), GameState::Kakuhen { n: 1, .. }));
+
+    game.enter_start_hole().unwrap();
+    assert!(matches!(game.state(), GameState::Normal { .. }));
+    assert!(game.snapshot().rng.is_exhausted());
+}
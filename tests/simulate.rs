@@ -0,0 +1,117 @@
+use pachislo::{
+    CONFIG_EXAMPLE, START_HOLE_PROBABILITY_EXAMPLE,
+    config::{BallsConfig, Config, Probability, SlotProbability},
+    simulate::Simulation,
+};
+
+const SIMULATION: Simulation = Simulation {
+    start_hole_probability: START_HOLE_PROBABILITY_EXAMPLE,
+    launches: 50_000,
+    sessions: 6,
+    seed: Some(7),
+};
+
+#[test]
+fn seeded_simulation_is_reproducible() {
+    let report = SIMULATION.run(&CONFIG_EXAMPLE).unwrap();
+
+    assert_eq!(report.sessions, 6);
+    assert_eq!(report.final_balls.len(), 6);
+    assert!(report.normal.spins > 0);
+    assert_eq!(report, SIMULATION.run(&CONFIG_EXAMPLE).unwrap());
+
+    let other = Simulation {
+        seed: Some(8),
+        ..SIMULATION
+    };
+    assert_ne!(report, other.run(&CONFIG_EXAMPLE).unwrap());
+}
+
+#[test]
+fn report_is_consistent() {
+    let report = SIMULATION.run(&CONFIG_EXAMPLE).unwrap();
+
+    assert!(report.launches <= SIMULATION.launches * SIMULATION.sessions);
+    assert_eq!(
+        report.rush_lengths.len(),
+        report.max_chain,
+        "the longest rush is the last bucket"
+    );
+    assert!(report.rush_count() <= report.normal.hits);
+    assert!(report.average_rush_length() >= 1.0);
+
+    // Rush balls are free
+    assert!(report.paid_launches < report.launches);
+    let net = report.balls_out as f64 - report.paid_launches as f64;
+    let expected = report.final_balls.iter().sum::<usize>() as f64
+        - (CONFIG_EXAMPLE.balls.init_balls * report.sessions) as f64;
+    assert_eq!(net, expected);
+    assert_eq!(
+        report.payout_ratio(),
+        report.balls_out as f64 / report.paid_launches as f64
+    );
+}
+
+#[test]
+fn losing_machine_busts_every_session() {
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 100,
+            ..CONFIG_EXAMPLE.balls
+        },
+        probability: Probability {
+            normal: SlotProbability::default(),
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+
+    let report = SIMULATION.run(&config).unwrap();
+
+    assert_eq!(report.busted(), SIMULATION.sessions);
+    assert_eq!(report.launches, 100 * SIMULATION.sessions);
+    assert_eq!(report.balls_out, 0);
+    assert_eq!(report.payout_ratio(), 0.0);
+    assert_eq!(report.normal.hits, 0);
+    assert_eq!(report.rush_count(), 0);
+}
+
+#[test]
+fn rush_running_at_session_end_is_counted() {
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            normal: SlotProbability {
+                win: 1.0,
+                ..SlotProbability::default()
+            },
+            ..CONFIG_EXAMPLE.probability
+        },
+        payout: None,
+    };
+    let simulation = Simulation {
+        start_hole_probability: 1.0,
+        launches: 1,
+        ..SIMULATION
+    };
+
+    // The only launch wins the jackpot and the session ends in the first rush round
+    let report = simulation.run(&config).unwrap();
+
+    assert_eq!(report.rush_lengths, [SIMULATION.sessions]);
+    assert_eq!(report.rush_count(), report.normal.hits);
+    assert_eq!(report.max_chain, 1);
+}
+
+#[test]
+fn invalid_config_is_rejected() {
+    let config = Config {
+        balls: BallsConfig {
+            init_balls: 0,
+            ..CONFIG_EXAMPLE.balls
+        },
+        ..CONFIG_EXAMPLE
+    };
+
+    assert!(SIMULATION.run(&config).is_err());
+}
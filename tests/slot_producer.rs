@@ -1,7 +1,12 @@
 use std::time::Instant;
 
-use pachislo::slot::SlotProducer;
-use rand::rngs::ThreadRng;
+use pachislo::{
+    lottery::{JackpotCategory, Lose, LotteryResult, Win},
+    slot::{
+        EffectKind, EffectStage, ReachConfig, ReachKind, ReachWeights, SlotOutcome, SlotProducer,
+    },
+};
+use rand::rngs::{StdRng, ThreadRng};
 
 #[test]
 fn win() {
@@ -69,3 +74,412 @@ fn big_lose() {
     let end = start.elapsed();
     println!("Elapsed time: {end:?}");
 }
+
+const REACH: ReachConfig = ReachConfig {
+    win: 1.0,
+    lose: 0.5,
+    win_kinds: ReachWeights {
+        normal: 0,
+        super_: 1,
+        premium: 1,
+    },
+    lose_kinds: ReachWeights {
+        normal: 1,
+        super_: 0,
+        premium: 0,
+    },
+};
+
+/// Whether every reel but the last one shows the same symbol, and the last one differs.
+fn is_miss(reels: &[u8]) -> bool {
+    let (last, rest) = reels.split_last().unwrap();
+    rest.iter().all(|symbol| symbol == &rest[0]) && last != &rest[0]
+}
+
+fn is_win(reels: &[u8]) -> bool {
+    reels.iter().all(|symbol| symbol == &reels[0])
+}
+
+#[test]
+fn reach_follows_the_config() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 42).with_reach(REACH);
+
+    let mut losing_reaches = 0;
+    for _ in 0..1000 {
+        let win = producer.produce_outcome(&LotteryResult::Win(Win::Default));
+        assert!(is_win(&win.reels));
+        assert!(matches!(
+            win.reach,
+            Some(ReachKind::Super | ReachKind::Premium)
+        ));
+        assert_eq!(win.revival, None);
+
+        let lose = producer.produce_outcome(&LotteryResult::Lose(Lose::Default));
+        match lose.reach {
+            Some(kind) => {
+                assert_eq!(kind, ReachKind::Normal);
+                assert!(is_miss(&lose.reels), "{lose:?}");
+                losing_reaches += 1;
+            }
+            None => assert!(!is_win(&lose.reels)),
+        }
+    }
+    assert!((400..600).contains(&losing_reaches));
+}
+
+#[test]
+fn fake_results_revive_the_reach_symbol() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 7).with_reach(REACH);
+
+    for _ in 0..1000 {
+        let SlotOutcome {
+            reels,
+            reach,
+            revival,
+        } = producer.produce_outcome(&LotteryResult::Win(Win::FakeWin));
+        let revival = revival.unwrap();
+        assert!(reach.is_some());
+        assert!(is_miss(&reels));
+        assert!(is_win(&revival));
+        assert_eq!(reels[0], revival[0]);
+
+        let SlotOutcome { reels, revival, .. } =
+            producer.produce_outcome(&LotteryResult::Lose(Lose::FakeLose));
+        let revival = revival.unwrap();
+        assert!(is_win(&reels));
+        assert!(is_miss(&revival));
+        assert_eq!(reels[..2], revival[..2]);
+    }
+}
+
+#[test]
+fn fake_lose_slips_by_the_reel_weights() {
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(3, vec![1, 3, 7, 9], 5)
+        .with_weights(vec![1, 3, 0, 4])
+        .with_reach(REACH);
+
+    for _ in 0..1000 {
+        let SlotOutcome { reels, revival, .. } =
+            producer.produce_outcome(&LotteryResult::Lose(Lose::FakeLose));
+        let revival = revival.unwrap();
+        assert!(is_win(&reels));
+        assert!(is_miss(&revival));
+        assert!(!revival.contains(&7), "{revival:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "Losing sequences need at least two reels")]
+fn lose_needs_two_reels() {
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(1, vec![1, 2], 0);
+    producer.produce_lose();
+}
+
+#[test]
+#[should_panic(expected = "Losing sequences need at least two reels")]
+fn weighted_lose_needs_two_reels() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(1, vec![1, 2], 0).with_weights(vec![1, 1]);
+    producer.produce_lose();
+}
+
+#[test]
+fn outcome_without_reach_matches_produce() {
+    let results = [
+        LotteryResult::Win(Win::Default),
+        LotteryResult::Win(Win::FakeWin),
+        LotteryResult::Lose(Lose::Default),
+        LotteryResult::Lose(Lose::FakeLose),
+    ];
+    let mut plain: SlotProducer<u8, StdRng> = SlotProducer::from_seed(3, (1..=9).collect(), 1);
+    let mut outcome: SlotProducer<u8, StdRng> = SlotProducer::from_seed(3, (1..=9).collect(), 1);
+
+    for result in results.iter().cycle().take(100) {
+        let (reels, revival) = plain.produce(result);
+        assert_eq!(
+            outcome.produce_outcome(result),
+            SlotOutcome {
+                reels,
+                reach: None,
+                revival,
+            }
+        );
+    }
+}
+
+#[test]
+fn single_reel_outcome_wins_without_reach() {
+    let win = LotteryResult::Win(Win::Default);
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(1, vec![1, 2], 1);
+    let outcome = producer.produce_outcome(&win);
+    assert_eq!(outcome.reels.len(), 1);
+    assert_eq!(outcome.reach, None);
+
+    // A reach that is never drawn for wins does not need a second reel either
+    let mut producer = producer.with_reach(ReachConfig { win: 0.0, ..REACH });
+    assert_eq!(producer.produce_outcome(&win).reach, None);
+}
+
+#[test]
+#[should_panic(expected = "Reaches need at least two reels")]
+fn single_reel_reach_needs_two_reels() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(1, vec![1, 2], 1).with_reach(REACH);
+    producer.produce_outcome(&LotteryResult::Win(Win::Default));
+}
+
+#[test]
+fn reels_follow_their_weights() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, vec![1, 3, 7, 9], 42).with_weights(vec![1, 3, 0, 4]);
+
+    let mut wins = [0; 10];
+    for _ in 0..8000 {
+        wins[usize::from(producer.produce_win()[0])] += 1;
+
+        let lose = producer.produce_lose();
+        assert!(!is_win(&lose));
+        assert!(!lose.contains(&7));
+    }
+    assert_eq!(wins[7], 0);
+    assert!((800..1200).contains(&wins[1]), "{wins:?}");
+    assert!((2700..3300).contains(&wins[3]), "{wins:?}");
+    assert!((3700..4300).contains(&wins[9]), "{wins:?}");
+}
+
+#[test]
+fn winning_symbols_depend_on_the_result() {
+    let choices = (1..=9).collect();
+    let mut sevens = vec![0; 9];
+    sevens[6] = 1;
+    let mut odd = vec![0; 9];
+    for weight in odd.iter_mut().step_by(2) {
+        *weight = 1;
+    }
+
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(3, choices, 3)
+        .with_win_weights(Win::Default, sevens)
+        .with_reach(REACH);
+    for _ in 0..100 {
+        let (win, _) = producer.produce(&LotteryResult::Win(Win::Default));
+        assert_eq!(win, [7, 7, 7]);
+        // Fake wins fall back to the weights of `Win::Default`
+        let (_, revival) = producer.produce(&LotteryResult::Win(Win::FakeWin));
+        assert_eq!(revival.unwrap(), [7, 7, 7]);
+    }
+
+    producer = producer.with_win_weights(Win::FakeWin, odd);
+    for _ in 0..100 {
+        let outcome = producer.produce_outcome(&LotteryResult::Win(Win::FakeWin));
+        assert_eq!(outcome.revival.unwrap()[0] % 2, 1);
+        let outcome = producer.produce_outcome(&LotteryResult::Win(Win::Default));
+        assert_eq!(outcome.reels, [7, 7, 7]);
+    }
+}
+
+#[test]
+fn weighted_constructor_pairs_symbols_with_weights() {
+    let mut producer: SlotProducer<char, ThreadRng> =
+        SlotProducer::weighted(2, vec![('a', 1), ('b', 0), ('c', 1)]);
+
+    for _ in 0..100 {
+        assert!(!producer.produce_win().contains(&'b'));
+        assert!(!producer.produce_lose().contains(&'b'));
+    }
+}
+
+#[test]
+#[should_panic(expected = "at least two symbols")]
+fn weights_need_two_symbols() {
+    let _: SlotProducer<u8, ThreadRng> = SlotProducer::weighted(3, vec![(1, 1), (2, 0)]);
+}
+
+#[test]
+fn jackpot_symbols_match_their_category() {
+    // Odd numbers lead into a rush, even numbers are normal bonuses, 7 is kakuhen only
+    let choices: Vec<u8> = (1..=9).collect();
+    let categories = choices
+        .iter()
+        .map(|&symbol| match symbol {
+            7 => JackpotCategory::Kakuhen,
+            _ if symbol % 2 == 1 => JackpotCategory::Rush,
+            _ => JackpotCategory::Normal,
+        })
+        .collect();
+    let mut weights = vec![1; 9];
+    weights[0] = 0;
+
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(3, choices, 5)
+        .with_win_weights(Win::Default, weights)
+        .with_categories(categories)
+        .with_reach(REACH);
+    for _ in 0..100 {
+        let outcome =
+            producer.produce_jackpot(&LotteryResult::Win(Win::Default), JackpotCategory::Rush);
+        assert!(
+            outcome
+                .reels
+                .iter()
+                .all(|&symbol| symbol == outcome.reels[0])
+        );
+        // Symbol 1 keeps its zero weight
+        assert!([3, 5, 9].contains(&outcome.reels[0]));
+
+        let outcome =
+            producer.produce_jackpot(&LotteryResult::Win(Win::FakeWin), JackpotCategory::Kakuhen);
+        assert_eq!(outcome.revival.unwrap(), [7, 7, 7]);
+
+        let outcome =
+            producer.produce_jackpot(&LotteryResult::Win(Win::Default), JackpotCategory::Normal);
+        assert_eq!(outcome.reels[0] % 2, 0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "No symbol can win")]
+fn jackpot_category_needs_a_symbol() {
+    let mut producer: SlotProducer<u8, ThreadRng> = SlotProducer::new(3, vec![1, 2])
+        .with_categories(vec![JackpotCategory::Rush, JackpotCategory::Normal]);
+    producer.produce_jackpot(&LotteryResult::Win(Win::Default), JackpotCategory::Kakuhen);
+}
+
+const STAGES: [EffectStage; 3] = [
+    EffectStage {
+        kind: EffectKind::Reach,
+        win: 1.0,
+        lose: 1.0,
+    },
+    EffectStage {
+        kind: EffectKind::SuperReach,
+        win: 1.0,
+        lose: 0.5,
+    },
+    EffectStage {
+        kind: EffectKind::Revival,
+        win: 0.5,
+        lose: 0.0,
+    },
+];
+
+#[test]
+fn frames_pass_stages_in_order() {
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 11).with_effects(STAGES.to_vec());
+
+    for _ in 0..1000 {
+        let frames = producer.produce_frames(&LotteryResult::Win(Win::FakeWin));
+        assert!((2..=3).contains(&frames.len()));
+        for (frame, stage) in frames.iter().zip(&STAGES) {
+            assert_eq!(frame.stage, Some(stage.kind));
+        }
+        let (last, missed) = frames.split_last().unwrap();
+        assert!(last.reels.iter().all(|&symbol| symbol == last.reels[0]));
+        for frame in missed {
+            assert_eq!(frame.reels[..2], last.reels[..2]);
+            assert_ne!(frame.reels[2], last.reels[2]);
+        }
+
+        let frames = producer.produce_frames(&LotteryResult::Lose(Lose::FakeLose));
+        assert!((2..=3).contains(&frames.len()));
+        let [.., shown, slip] = frames.as_slice() else {
+            unreachable!()
+        };
+        assert!(is_win(&shown.reels));
+        assert_eq!(slip.stage, None);
+        assert!(is_miss(&slip.reels));
+        assert_eq!(slip.reels[..2], shown.reels[..2]);
+    }
+}
+
+#[test]
+fn stages_fail_in_their_own_way() {
+    let stages =
+        [EffectKind::Reach, EffectKind::Reversal, EffectKind::Revival].map(|kind| EffectStage {
+            kind,
+            win: 1.0,
+            lose: 1.0,
+        });
+    let mut producer: SlotProducer<u8, StdRng> =
+        SlotProducer::from_seed(3, (1..=9).collect(), 3).with_effects(stages.to_vec());
+
+    for _ in 0..1000 {
+        let frames = producer.produce_frames(&LotteryResult::Lose(Lose::Default));
+        let [reach, reversal, revival] = frames.as_slice() else {
+            panic!("{frames:?}");
+        };
+        assert!(is_miss(&reach.reels));
+        // The last reel turns onto the reach symbol while the one before slips off
+        assert_eq!(reversal.reels[0], reach.reels[0]);
+        assert_eq!(reversal.reels[1], reach.reels[2]);
+        assert_eq!(reversal.reels[2], reach.reels[0]);
+        assert_eq!(revival.stage, Some(EffectKind::Revival));
+        assert!(!is_win(&revival.reels));
+
+        let frames = producer.produce_frames(&LotteryResult::Win(Win::Default));
+        assert_eq!(frames.len(), 3);
+        assert!(is_miss(&frames[0].reels));
+        assert!(!is_win(&frames[1].reels));
+        assert!(is_win(&frames[2].reels));
+    }
+}
+
+#[test]
+fn frames_without_stages_stop_plainly() {
+    let mut producer: SlotProducer<u8, ThreadRng> = SlotProducer::new(3, (1..=9).collect());
+
+    let frames = producer.produce_frames(&LotteryResult::Win(Win::Default));
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].stage, None);
+    assert!(is_win(&frames[0].reels));
+
+    let frames = producer.produce_frames(&LotteryResult::Lose(Lose::Default));
+    assert_eq!(frames.len(), 1);
+    assert!(!is_win(&frames[0].reels));
+
+    // Fake results still flip, as in `produce`
+    let frames = producer.produce_frames(&LotteryResult::Win(Win::FakeWin));
+    assert_eq!(frames.len(), 2);
+    assert!(!is_win(&frames[0].reels));
+    assert_eq!(frames[1].stage, Some(EffectKind::Revival));
+    assert!(is_win(&frames[1].reels));
+
+    let frames = producer.produce_frames(&LotteryResult::Lose(Lose::FakeLose));
+    assert_eq!(frames.len(), 2);
+    assert!(is_win(&frames[0].reels));
+    assert!(is_miss(&frames[1].reels));
+}
+
+#[test]
+fn single_reel_win_needs_no_stage() {
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(1, vec![1, 2], 1);
+
+    let frames = producer.produce_frames(&LotteryResult::Win(Win::Default));
+    assert_eq!(frames.len(), 1);
+    assert_eq!(frames[0].stage, None);
+    assert_eq!(frames[0].reels.len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Reaches need at least two reels")]
+fn single_reel_stage_needs_two_reels() {
+    let mut producer: SlotProducer<u8, StdRng> = SlotProducer::from_seed(1, vec![1, 2], 1)
+        .with_effects(vec![EffectStage {
+            kind: EffectKind::Reach,
+            win: 1.0,
+            lose: 0.0,
+        }]);
+    producer.produce_frames(&LotteryResult::Win(Win::Default));
+}
+
+#[test]
+#[should_panic(expected = "between 0.0 and 1.0")]
+fn effect_probabilities_are_validated() {
+    let _: SlotProducer<u8, ThreadRng> =
+        SlotProducer::new(3, vec![1, 2]).with_effects(vec![EffectStage {
+            kind: EffectKind::Reversal,
+            win: 1.5,
+            lose: 0.0,
+        }]);
+}
@@ -0,0 +1,218 @@
+use pachislo::{
+    CONFIG_EXAMPLE,
+    config::{Config, Payout, Probability, RoundWeight, RushContinueFn},
+    game::{Bonus, Game, GameSnapshot, GameState, Hold},
+};
+use rand::{SeedableRng, rngs::StdRng};
+
+mod common;
+
+use common::{ALWAYS, NullInput, Record, RecordingOutput};
+
+/// Launches `launches` balls, every other one entering the start hole.
+fn launch<F: RushContinueFn, R: rand::Rng>(
+    game: &mut Game<NullInput, RecordingOutput, F, R>,
+    launches: usize,
+) {
+    for i in 0..launches {
+        if game.launch_ball().is_err() {
+            break;
+        }
+        if i % 2 == 0 && *game.state() != GameState::Uninitialized {
+            game.enter_start_hole().unwrap();
+        }
+    }
+}
+
+fn output() -> RecordingOutput {
+    RecordingOutput {
+        records: Vec::new(),
+    }
+}
+
+#[test]
+fn restored_game_continues_like_the_original() {
+    let mut game: Game<_, _, _, StdRng> =
+        Game::from_seed(CONFIG_EXAMPLE, NullInput, output(), 42).unwrap();
+    game.start().unwrap();
+    launch(&mut game, 300);
+
+    let snapshot = game.snapshot();
+    let mut restored = Game::restore(snapshot, NullInput, output()).unwrap();
+    assert_eq!(restored.state(), game.state());
+    assert_eq!(restored.hold(), game.hold());
+
+    let before = game.output().records.len();
+    launch(&mut game, 300);
+    launch(&mut restored, 300);
+
+    assert_eq!(restored.state(), game.state());
+    assert_eq!(restored.output().records, game.output().records[before..]);
+}
+
+#[test]
+fn game_can_start_from_any_state() {
+    let config: Config = Config {
+        probability: Probability {
+            rush: ALWAYS,
+            rush_continue: ALWAYS,
+            rush_continue_fn: |_| 1.0,
+            ..CONFIG_EXAMPLE.probability
+        },
+        ..CONFIG_EXAMPLE
+    };
+    let snapshot = GameSnapshot {
+        config,
+        state: GameState::Rush {
+            balls: 500,
+            rush_balls: 1,
+            n: 7,
+        },
+        before_state: None,
+        hold: Hold::default(),
+        bonus: None,
+        rush_start_balls: 400,
+        rng: StdRng::seed_from_u64(0),
+    };
+
+    let mut game = Game::restore(snapshot, NullInput, output()).unwrap();
+    game.cause_lottery();
+
+    let balls = CONFIG_EXAMPLE.balls;
+    assert_eq!(
+        *game.state(),
+        GameState::Rush {
+            balls: 500 + balls.incremental_balls,
+            rush_balls: 1 + balls.incremental_rush,
+            n: 8,
+        }
+    );
+    assert!(matches!(
+        game.output().records.as_slice(),
+        [Record::Rush(_), Record::RushContinue(_)]
+    ));
+}
+
+#[test]
+fn restore_rejects_an_invalid_config() {
+    let mut config = CONFIG_EXAMPLE;
+    config.probability.normal.win = 2.0;
+    let snapshot = GameSnapshot {
+        config,
+        state: GameState::Uninitialized,
+        before_state: None,
+        hold: Hold::default(),
+        bonus: None,
+        rush_start_balls: 0,
+        rng: StdRng::seed_from_u64(0),
+    };
+
+    assert!(Game::restore(snapshot, NullInput, output()).is_err());
+}
+
+/// Snapshot of a started game in the middle of `bonus`.
+fn bonus_snapshot(payout: Option<Payout>, bonus: Bonus) -> GameSnapshot<fn(usize) -> f64, StdRng> {
+    GameSnapshot {
+        config: Config {
+            payout,
+            ..CONFIG_EXAMPLE
+        },
+        state: GameState::Normal {
+            balls: 500,
+            spins: 0,
+        },
+        before_state: None,
+        hold: Hold::default(),
+        bonus: Some(bonus),
+        rush_start_balls: 0,
+        rng: StdRng::seed_from_u64(0),
+    }
+}
+
+fn payout() -> Payout {
+    let table = vec![RoundWeight {
+        rounds: 10,
+        weight: 1,
+    }];
+    Payout {
+        normal: table.clone(),
+        rush: table,
+        counts: 10,
+        balls_per_count: 15,
+    }
+}
+
+const BONUS: Bonus = Bonus {
+    rounds: 10,
+    round: 3,
+    count: 4,
+};
+
+#[test]
+fn restore_accepts_a_bonus_in_progress() {
+    let game = Game::restore(bonus_snapshot(Some(payout()), BONUS), NullInput, output()).unwrap();
+
+    assert_eq!(game.bonus(), Some(&BONUS));
+}
+
+#[test]
+fn restore_rejects_a_bonus_without_payout() {
+    assert!(Game::restore(bonus_snapshot(None, BONUS), NullInput, output()).is_err());
+}
+
+#[test]
+fn restore_rejects_a_bonus_round_out_of_range() {
+    for round in [0, 11] {
+        let bonus = Bonus { round, ..BONUS };
+        assert!(
+            Game::restore(bonus_snapshot(Some(payout()), bonus), NullInput, output()).is_err(),
+            "round {round} was accepted"
+        );
+    }
+}
+
+#[test]
+fn restore_rejects_a_full_bonus_round() {
+    let bonus = Bonus { count: 10, ..BONUS };
+
+    assert!(Game::restore(bonus_snapshot(Some(payout()), bonus), NullInput, output()).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn snapshot_survives_serialization() {
+    use pachislo::curve::ContinueCurve;
+    use rand_chacha::ChaCha12Rng;
+
+    let config = Config {
+        balls: CONFIG_EXAMPLE.balls,
+        probability: Probability {
+            rush_continue_fn: ContinueCurve::Geometric { ratio: 0.9 },
+            normal: CONFIG_EXAMPLE.probability.normal,
+            rush: CONFIG_EXAMPLE.probability.rush,
+            rush_continue: CONFIG_EXAMPLE.probability.rush_continue,
+            rush_continue_horizon: CONFIG_EXAMPLE.probability.rush_continue_horizon,
+            kakuhen: CONFIG_EXAMPLE.probability.kakuhen,
+            kakuhen_entry: CONFIG_EXAMPLE.probability.kakuhen_entry,
+            kakuhen_fall: CONFIG_EXAMPLE.probability.kakuhen_fall,
+            jitan_start_hole: CONFIG_EXAMPLE.probability.jitan_start_hole,
+            rush_entry: CONFIG_EXAMPLE.probability.rush_entry,
+        },
+        payout: None,
+    };
+    let mut game: Game<_, _, _, ChaCha12Rng> =
+        Game::from_seed(config, NullInput, output(), 7).unwrap();
+    game.start().unwrap();
+    launch(&mut game, 300);
+
+    let json = serde_json::to_string(&game.snapshot()).unwrap();
+    let snapshot: GameSnapshot<ContinueCurve, ChaCha12Rng> = serde_json::from_str(&json).unwrap();
+    let mut restored = Game::restore(snapshot, NullInput, output()).unwrap();
+    assert_eq!(restored.state(), game.state());
+
+    let before = game.output().records.len();
+    launch(&mut game, 300);
+    launch(&mut restored, 300);
+    assert_eq!(restored.state(), game.state());
+    assert_eq!(restored.output().records, game.output().records[before..]);
+}